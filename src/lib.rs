@@ -33,7 +33,15 @@
 //! vec_cursor.seek(-2);
 //! assert_eq!(vec_cursor.get().unwrap(), &2);
 //! ```
-use std::ops::{Deref, DerefMut};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet, TryReserveError, VecDeque},
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{DefaultHasher, Hash, Hasher},
+    ops::{Deref, DerefMut, Range},
+    panic::AssertUnwindSafe,
+};
 
 // TODO: Convert to no_std
 
@@ -94,6 +102,15 @@ use std::ops::{Deref, DerefMut};
 /// Instead of using indexing directly, consider using [`Self::get()`] method to
 /// safely access the element.
 ///
+/// # Construction
+/// Besides the public [`Self::inner`] and [`Self::pos`] fields, the cursor
+/// keeps private state such as marks and history. It therefore cannot be built
+/// with a struct literal or destructured exhaustively. Use [`From`] or
+/// [`Self::try_new()`] instead, and `..` when destructuring.
+///
+/// Cloning a cursor copies its elements, position and private state, except
+/// for the callback registered through [`Self::set_on_move()`].
+///
 /// # Possible Undefined Behavior
 /// [Any modification to
 /// [`DerefMut`] will keep the cursor position unchanged. Accessing the cursor
@@ -129,6 +146,21 @@ pub struct CycleCursor<T> {
     pub inner: Vec<T>,
     /// Cursor pointing to pos
     pub pos: Option<usize>,
+    /// Named bookmarks of cursor positions
+    marks: BTreeMap<String, Option<usize>>,
+    /// Whether [`Self::get()`] clamps a stale position instead of panicking
+    autofix: bool,
+    /// Sub-step movement carried over by [`Self::seek_fractional()`]
+    fractional_seek: f64,
+    /// Positions visited by movement methods, oldest first
+    history: VecDeque<usize>,
+    /// Maximum number of recorded positions, where `0` disables recording
+    history_capacity: usize,
+    /// Callback invoked with the old and new position on every move
+    on_move: OnMove,
+    /// Per-element availability mask, where [`None`] makes every element
+    /// available
+    availability: Option<Vec<bool>>,
 }
 
 /// Convert from an iterator to a `CycleCursor`
@@ -138,15 +170,189 @@ where
     I::Item: Clone,
 {
     fn from(inner: I) -> Self {
-        Self {
-            inner: inner.into_iter().collect(),
-            pos: None,
+        Self::from_parts(inner.into_iter().collect(), None)
+    }
+}
+
+/// Errors returned by fallible `CycleCursor` operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CursorError {
+    /// A position or index does not lie within the bounds of the underlying
+    /// vector.
+    OutOfBounds,
+    /// No mark with the requested name exists.
+    MarkNotFound,
+    /// The cursor position lies beyond the underlying vector, which was
+    /// modified after the cursor was placed.
+    StalePosition,
+    /// The underlying vector holds no elements.
+    Empty,
+}
+
+impl Display for CursorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds => write!(f, "Position is out of bounds of the underlying vec"),
+            Self::MarkNotFound => write!(f, "No mark exists with the requested name"),
+            Self::StalePosition => {
+                write!(f, "Position is stale as the underlying vec was modified")
+            }
+            Self::Empty => write!(f, "The underlying vec is empty"),
+        }
+    }
+}
+
+impl Error for CursorError {}
+
+/// Snapshot of a cursor position created by [`CycleCursor::bookmark()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CursorBookmark {
+    /// Cursor position at the time of the bookmark
+    pos: Option<usize>,
+    /// Length of the underlying vector at the time of the bookmark
+    len: usize,
+}
+
+/// Fingerprint of a cursor state created by [`CycleCursor::checkpoint()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Length of the underlying vector at the time of the checkpoint
+    len: usize,
+    /// Cursor position at the time of the checkpoint
+    pos: Option<usize>,
+    /// Hash of the underlying vector at the time of the checkpoint
+    hash: u64,
+}
+
+/// Commonly derived facts about a cursor state, created by
+/// [`CycleCursor::summary()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CursorSummary {
+    /// Length of the underlying vector
+    pub len: usize,
+    /// Raw cursor position
+    pub pos: Option<usize>,
+    /// Whether the underlying vector is empty
+    pub is_empty: bool,
+    /// Whether the cursor points to a position
+    pub is_initialized: bool,
+    /// Elements strictly after the cursor, see
+    /// [`CycleCursor::remaining_forward()`]
+    pub remaining_forward: Option<usize>,
+    /// Elements strictly before the cursor, see
+    /// [`CycleCursor::remaining_backward()`]
+    pub remaining_backward: Option<usize>,
+}
+
+/// Callback invoked with the old and new cursor position
+type MoveCallback = Box<dyn FnMut(Option<usize>, Option<usize>) + Send>;
+
+/// Callback registered through [`CycleCursor::set_on_move()`]. Cloning a
+/// cursor does not clone its callback.
+///
+/// A callback that panics is dropped while unwinding and never observed again,
+/// so holding one does not make the cursor unwind unsafe.
+#[derive(Default)]
+struct OnMove(Option<AssertUnwindSafe<MoveCallback>>);
+
+impl Clone for OnMove {
+    fn clone(&self) -> Self {
+        Self(None)
+    }
+}
+
+impl Debug for OnMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "Some(..)"),
+            None => write!(f, "None"),
         }
     }
 }
 
 /// Implementations for `CycleCursor`
 impl<T> CycleCursor<T> {
+    /// Create a cursor from raw parts, validating the position against the
+    /// underlying vector.
+    ///
+    /// This replaces constructing the struct literally, which the private
+    /// state of the cursor rules out, and rejects invalid positions, e.g. when
+    /// deserializing from untrusted sources.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::OutOfBounds`] if `pos` is [`Some`] and not
+    /// strictly less than the length of `inner`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::{CursorError, CycleCursor};
+    /// #
+    /// let cycle_cursor = CycleCursor::try_new(vec![1, 2, 3, 4], Some(2)).unwrap();
+    /// assert_eq!(cycle_cursor.get().unwrap(), &3);
+    ///
+    /// let result = CycleCursor::try_new(vec![1, 2, 3, 4], Some(4));
+    /// assert_eq!(result.unwrap_err(), CursorError::OutOfBounds);
+    /// ```
+    pub fn try_new(inner: Vec<T>, pos: Option<usize>) -> Result<Self, CursorError> {
+        if pos.is_some_and(|index| index >= inner.len()) {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        Ok(Self::from_parts(inner, pos))
+    }
+
+    /// Create a cursor from raw parts without any validation.
+    const fn from_parts(inner: Vec<T>, pos: Option<usize>) -> Self {
+        Self {
+            inner,
+            pos,
+            marks: BTreeMap::new(),
+            autofix: false,
+            fractional_seek: 0.0,
+            history: VecDeque::new(),
+            history_capacity: 0,
+            on_move: OnMove(None),
+            availability: None,
+        }
+    }
+
+    /// Move the cursor to `pos`, notifying the callback registered through
+    /// [`Self::set_on_move()`] if the position changed.
+    fn move_to(&mut self, pos: Option<usize>) {
+        let old_pos = self.pos;
+        self.pos = pos;
+
+        if old_pos == pos {
+            return;
+        }
+
+        // The callback is taken out while it runs, so a panicking callback is
+        // dropped during unwinding instead of being invoked again.
+        if let Some(mut on_move) = self.on_move.0.take() {
+            on_move(old_pos, pos);
+            self.on_move = OnMove(Some(on_move));
+        }
+    }
+
+    /// Move the cursor to `pos`, recording it in the history if enabled
+    /// through [`Self::enable_history()`].
+    fn land_on(&mut self, pos: Option<usize>) {
+        self.move_to(pos);
+
+        let Some(pos) = pos else {
+            return;
+        };
+        if self.history_capacity == 0 {
+            return;
+        }
+
+        if self.history.len() == self.history_capacity {
+            let _: Option<usize> = self.history.pop_front();
+        }
+        self.history.push_back(pos);
+    }
+
     /// Moves the cursor to the next element. If no element exists, wrap back to
     /// the first element.
     pub fn cycle_next(&mut self) {
@@ -157,7 +363,7 @@ impl<T> CycleCursor<T> {
 
         #[allow(clippy::integer_division_remainder_used)]
         let pos = (self.pos.unwrap_or(max_items - 1) + max_items + 1) % max_items;
-        self.pos = Some(pos);
+        self.land_on(Some(pos));
     }
 
     /// Moves the cursor to the previous element. If no element exists, wrap to
@@ -170,7 +376,7 @@ impl<T> CycleCursor<T> {
 
         #[allow(clippy::integer_division_remainder_used)]
         let pos = (self.pos.unwrap_or(max_items) + max_items - 1) % max_items;
-        self.pos = Some(pos);
+        self.land_on(Some(pos));
     }
 
     /// Peek element at an offset from the current cursor position
@@ -250,9 +456,16 @@ impl<T> CycleCursor<T> {
     /// assert_eq!(cycle_cursor.get(), None);
     /// ```
     pub fn seek(&mut self, seek_distance: isize) {
+        let pos = self.seek_from(self.pos, seek_distance);
+        self.land_on(pos);
+    }
+
+    /// Compute the position [`Self::seek()`] would move to from `pos` by an
+    /// offset, without moving the cursor.
+    fn seek_from(&self, pos: Option<usize>, seek_distance: isize) -> Option<usize> {
         let max_items = self.inner.len();
         if max_items == 0 {
-            return;
+            return pos;
         }
 
         let norm_seek_distance = if seek_distance < 0 {
@@ -262,21 +475,29 @@ impl<T> CycleCursor<T> {
         } as usize;
 
         #[allow(clippy::integer_division_remainder_used)]
-        let pos = (self.pos.unwrap_or(max_items - 1) + max_items + norm_seek_distance) % max_items;
-        self.pos = Some(pos);
+        let pos = (pos.unwrap_or(max_items - 1) + max_items + norm_seek_distance) % max_items;
+        Some(pos)
     }
 
     /// Safely access currently pointed element from [`Self`]. Consider using
     /// this instead of directly dereferencing into inner [`Vec`]
     ///
+    /// If autofix is enabled through [`Self::set_autofix()`], a stale position
+    /// is clamped to the last element instead.
+    ///
     /// # Panics
     /// Calling this method will panic if the underlying vector has been altered
-    /// and the position marker drops below the maximum length of the vector.
+    /// and the position marker drops below the maximum length of the vector,
+    /// unless autofix is enabled.
     pub fn get(&self) -> Option<&T> {
         if self.pos.is_none() {
             return None;
         }
 
+        if self.autofix && self.pos.unwrap() >= self.inner.len() {
+            return self.inner.last();
+        }
+
         if self.pos.unwrap() >= self.inner.len() {
             // TODO: Consider changing to Result/bail!()
             panic!(
@@ -288,160 +509,7941 @@ impl<T> CycleCursor<T> {
         // TODO: Move to use proper error system. Remove all unwrap calls
         self.inner.get(self.pos.unwrap())
     }
-}
-
-impl<T> Deref for CycleCursor<T> {
-    type Target = Vec<T>;
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+    /// Binary search the underlying vector for a value and move the cursor to
+    /// it if found.
+    ///
+    /// On a match the cursor is moved to the matched element and `Ok(index)`
+    /// is returned. Otherwise `Err(index)` is returned with the index where
+    /// the value could be inserted to keep the order, and the cursor is left
+    /// untouched. This is the cursor-positioning analogue of
+    /// [`slice::binary_search`].
+    ///
+    /// The underlying vector is assumed to be sorted. If it is not, the
+    /// returned result is unspecified and meaningless.
+    ///
+    /// # Errors
+    /// Returns `Err(index)` with the insertion point when the value is not
+    /// found.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 3, 5, 7];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.binary_search_seek(&5), Ok(2));
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    ///
+    /// assert_eq!(cycle_cursor.binary_search_seek(&4), Err(2));
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    /// ```
+    pub fn binary_search_seek(&mut self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.inner
+            .binary_search(value)
+            .inspect(|&index| self.land_on(Some(index)))
     }
-}
 
-impl<T> DerefMut for CycleCursor<T> {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.inner
+    /// Compute a single hash combining the underlying vector and the cursor
+    /// position.
+    ///
+    /// This is meant for cheap change detection, letting callers check whether
+    /// anything changed without storing a full clone. Unlike a [`Hash`]
+    /// implementation it returns a concrete `u64` computed with a fixed hasher,
+    /// so the value is stable for the duration of a run.
+    ///
+    /// The returned value is not stable across crate or compiler versions and
+    /// must not be persisted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// let checksum = cycle_cursor.state_hash();
+    ///
+    /// cycle_cursor.cycle_next();
+    /// assert_ne!(cycle_cursor.state_hash(), checksum);
+    /// ```
+    #[must_use]
+    pub fn state_hash(&self) -> u64
+    where
+        T: Hash,
+    {
+        let mut hasher = self.inner_hasher();
+        self.pos.hash(&mut hasher);
+        hasher.finish()
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::{
-        collections::BTreeSet,
-        panic::{self, UnwindSafe},
-    };
-
-    use super::*;
 
-    fn assert_panic<F, R>(panic_fn: F)
+    /// Hasher fed with the underlying vector, shared by
+    /// [`Self::state_hash()`] and [`Self::checkpoint()`].
+    fn inner_hasher(&self) -> DefaultHasher
     where
-        F: FnOnce() -> R + UnwindSafe,
+        T: Hash,
     {
-        let panic_hook = panic::take_hook();
-        panic::set_hook(Box::new(|_| {}));
-        let result = panic::catch_unwind(panic_fn);
-        assert!(result.is_err());
-        panic::set_hook(panic_hook);
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        hasher
     }
 
-    #[test]
-    fn cursor_cycle_next() {
-        let source = vec![1, 2, 3, 4];
-        let mut cursor = CycleCursor::from(source);
-
-        assert_eq!(cursor.pos, None);
-        cursor.cycle_next();
-        assert_eq!(cursor.get().unwrap(), &1);
-        cursor.cycle_next();
-        cursor.cycle_next();
-        cursor.cycle_next();
-        assert_eq!(cursor.get().unwrap(), &4);
-        cursor.cycle_next();
-        assert_eq!(cursor.get().unwrap(), &1);
-    }
+    /// Split the underlying vector at the cursor, returning the tail starting
+    /// at the pointed element as a new cursor.
+    ///
+    /// Like [`Vec::split_off`], `self` is truncated to the elements before the
+    /// cursor while the returned cursor owns the elements from the cursor to
+    /// the end, pointing to its first element. Since the pointed element moves
+    /// to the returned cursor, the position of `self` is reset to [`None`]
+    /// rather than clamped.
+    ///
+    /// If the cursor points to [`None`], `self` is left untouched and an empty
+    /// cursor is returned.
+    ///
+    /// # Panics
+    /// Calling this method will panic if the underlying vector has been altered
+    /// and the position marker drops below the maximum length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let tail = cycle_cursor.split_off_at_cursor();
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2]);
+    /// assert_eq!(cycle_cursor.pos, None);
+    /// assert_eq!(tail.inner, vec![3, 4]);
+    /// assert_eq!(tail.get().unwrap(), &3);
+    /// ```
+    #[must_use]
+    pub fn split_off_at_cursor(&mut self) -> Self {
+        let Some(pos) = self.pos.take() else {
+            return Self::from_parts(Vec::new(), None);
+        };
 
-    #[test]
-    fn cursor_cycle_next_empty_vec() {
-        let source: Vec<usize> = vec![];
-        let mut cursor = CycleCursor::from(source);
+        let tail = self.inner.split_off(pos);
+        let tail_pos = (!tail.is_empty()).then_some(0);
 
-        assert_eq!(cursor.pos, None);
-        cursor.cycle_next();
-        assert_eq!(cursor.pos, None);
-        assert_eq!(cursor.get(), None);
+        Self::from_parts(tail, tail_pos)
     }
 
-    #[test]
-    fn cursor_cycle_prev() {
-        let source = BTreeSet::from([1, 2, 3, 4]);
-        let mut cursor = CycleCursor::from(source);
-
-        assert_eq!(cursor.pos, None);
-        cursor.cycle_prev();
-        assert_eq!(cursor.get().unwrap(), &4);
-        cursor.cycle_prev();
-        cursor.cycle_prev();
-        cursor.cycle_prev();
-        assert_eq!(cursor.get().unwrap(), &1);
-        cursor.cycle_prev();
-        assert_eq!(cursor.get().unwrap(), &4);
+    /// Fold over every element exactly once in cursor order, starting at the
+    /// pointed element and wrapping around.
+    ///
+    /// If the cursor points to [`None`], the fold starts at the first element.
+    /// For an empty vector `init` is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let sum = cycle_cursor.fold_from_cursor(0, |acc, item| acc + item);
+    /// assert_eq!(sum, 10);
+    ///
+    /// let order = cycle_cursor.fold_from_cursor(0, |acc, item| acc * 10 + item);
+    /// assert_eq!(order, 3412);
+    /// ```
+    #[must_use]
+    pub fn fold_from_cursor<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter_from_cursor().fold(init, f)
     }
 
-    #[test]
-    fn cursor_cycle_prev_empty_vec() {
-        let source: Vec<usize> = vec![];
-        let mut cursor = CycleCursor::from(source);
-
-        assert_eq!(cursor.pos, None);
-        cursor.cycle_prev();
-        assert_eq!(cursor.pos, None);
-        assert_eq!(cursor.get(), None);
+    /// Iterate over every element exactly once in cursor order, starting at
+    /// the pointed element (or the first element if there is none) and
+    /// wrapping around.
+    fn iter_from_cursor(&self) -> impl Iterator<Item = &T> {
+        let (tail, head) = self.spans_from_cursor();
+        tail.iter().chain(head)
     }
 
-    #[test]
-    fn cursor_peek() {
-        let source = vec![1, 2, 3, 4];
-        let mut cursor = CycleCursor::from(source);
-
-        // Cursor on None
-        assert_eq!(cursor.peek(3).unwrap(), &3);
-
-        // Move to first element
-        cursor.cycle_next();
+    /// Index a pass in cursor order starts from. This is the cursor position,
+    /// or the first element if the cursor points to [`None`] or is stale.
+    fn start_index(&self) -> usize {
+        self.current_index().unwrap_or(0)
+    }
 
-        assert_eq!(cursor.peek(3).unwrap(), &4);
+    /// Group consecutive elements with equal keys into runs over one pass in
+    /// cursor order, starting at the pointed element (or the first element if
+    /// there is none).
+    ///
+    /// Runs are never merged across the end of the pass. If the last run has
+    /// the same key as the first run, both are kept as separate groups so the
+    /// first group always starts at the cursor. For an empty vector an empty
+    /// [`Vec`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 1, 2, 2, 2, 1];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let runs = cycle_cursor.group_runs(|item| *item);
+    /// assert_eq!(runs, vec![vec![&1], vec![&2, &2, &2], vec![&1, &1]]);
+    /// ```
+    #[must_use]
+    pub fn group_runs<K: PartialEq, F: FnMut(&T) -> K>(&self, mut key: F) -> Vec<Vec<&T>> {
+        let mut runs: Vec<Vec<&T>> = Vec::new();
+        let mut last_key = None;
 
-        cursor.cycle_next();
-        cursor.cycle_next();
+        for item in self.iter_from_cursor() {
+            let item_key = key(item);
+            match runs.last_mut() {
+                Some(run) if last_key.as_ref() == Some(&item_key) => run.push(item),
+                _ => runs.push(vec![item]),
+            }
+            last_key = Some(item_key);
+        }
 
-        assert_eq!(cursor.peek(3).unwrap(), &2);
-        assert_eq!(cursor.peek(-3).unwrap(), &4);
+        runs
     }
 
-    #[test]
-    fn cursor_peek_empty_vec() {
-        let source: Vec<usize> = vec![];
-        let cursor = CycleCursor::from(source);
-
-        assert_eq!(cursor.pos, None);
-        assert_eq!(cursor.peek(2), None);
-        assert_eq!(cursor.get(), None);
+    /// Replace the underlying vector and reset the cursor to [`None`].
+    ///
+    /// Prefer this over assigning to [`Self::inner`] directly, which may leave
+    /// a stale position behind.
+    pub fn set_inner(&mut self, inner: Vec<T>) {
+        self.inner = inner;
+        self.pos = None;
     }
 
-    #[test]
-    fn cursor_seek() {
-        let source = vec![1, 2, 3, 4];
-        let mut cursor = CycleCursor::from(source);
-
-        cursor.seek(6);
-        assert_eq!(cursor.get().unwrap(), &2);
+    /// Replace the underlying vector while keeping the cursor position,
+    /// clamped to the bounds of the new vector.
+    ///
+    /// If the new vector is empty, the cursor is reset to [`None`]. A cursor
+    /// pointing to [`None`] stays that way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_prev();
+    ///
+    /// cycle_cursor.set_inner_keep_pos(vec![5, 6]);
+    /// assert_eq!(cycle_cursor.pos.unwrap(), 1);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &6);
+    /// ```
+    pub fn set_inner_keep_pos(&mut self, inner: Vec<T>) {
+        self.pos = self
+            .pos
+            .zip(inner.len().checked_sub(1))
+            .map(|(pos, last_index)| pos.min(last_index));
+        self.inner = inner;
+    }
+
+    /// Iterate over one pass in cursor order, pairing each element with the
+    /// number of forward steps from the cursor.
+    ///
+    /// The pointed element is yielded first with step `0`. If the cursor points
+    /// to [`None`], the pass starts at the first element. Nothing is yielded
+    /// for an empty vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let numbered: Vec<_> = cycle_cursor.enumerate_from_cursor().collect();
+    /// assert_eq!(numbered, vec![(0, &3), (1, &4), (2, &1), (3, &2)]);
+    /// ```
+    pub fn enumerate_from_cursor(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.iter_from_cursor().enumerate()
+    }
+
+    /// Mirror the cursor position around the center of the underlying vector,
+    /// moving it from `pos` to `len - 1 - pos` without changing the data.
+    ///
+    /// This is a no-op if the cursor points to [`None`] or the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.reflect_pos();
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    /// ```
+    pub fn reflect_pos(&mut self) {
+        let Some(pos) = self.pos else {
+            return;
+        };
+
+        if let Some(reflected) = self
+            .inner
+            .len()
+            .checked_sub(1)
+            .and_then(|last_index| last_index.checked_sub(pos))
+        {
+            self.land_on(Some(reflected));
+        }
+    }
+
+    /// Remove every duplicate element from the underlying vector, keeping only
+    /// the first occurrence of each value.
+    ///
+    /// Unlike [`Vec::dedup`] this removes duplicates anywhere in the vector,
+    /// not just consecutive ones. The cursor is relocated to the surviving
+    /// first occurrence of the pointed value, so if it pointed to a later
+    /// duplicate it lands on the kept earlier copy. A cursor pointing to
+    /// [`None`] stays that way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 1, 3, 2];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_prev();
+    ///
+    /// cycle_cursor.dedup_all_cursor();
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2, 3]);
+    /// assert_eq!(cycle_cursor.pos.unwrap(), 1);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn dedup_all_cursor(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        let mut keep = Vec::with_capacity(self.inner.len());
+        let mut new_pos = None;
+
+        {
+            let mut first_indices: HashMap<&T, usize> = HashMap::new();
+
+            for (index, item) in self.inner.iter().enumerate() {
+                let next_index = first_indices.len();
+                let kept_index = *first_indices.entry(item).or_insert(next_index);
+                keep.push(kept_index == next_index);
+
+                if self.pos == Some(index) {
+                    new_pos = Some(kept_index);
+                }
+            }
+        }
+
+        let mut keep_iter = keep.into_iter();
+        self.inner.retain(|_| keep_iter.next().unwrap_or(true));
+        self.pos = new_pos;
+    }
+
+    /// Rotate the underlying vector so the pointed element sits at the middle
+    /// index `len / 2`, keeping [`Self::get()`] invariant.
+    ///
+    /// This lets a fixed viewport always show context on both sides of the
+    /// cursor. This is a no-op if the cursor points to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.rotate_to_center();
+    /// assert_eq!(cycle_cursor.inner, vec![4, 5, 1, 2, 3]);
+    /// assert_eq!(cycle_cursor.pos.unwrap(), 2);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    /// ```
+    pub fn rotate_to_center(&mut self) {
+        let target = self.inner.len() / 2;
+        self.rotate_pos_to(target);
+    }
+
+    /// Rotate the underlying vector so the pointed element lands at `target`,
+    /// moving the cursor along with it.
+    ///
+    /// This is a no-op if the cursor points to [`None`] or is stale. `target`
+    /// must be within bounds of the underlying vector.
+    fn rotate_pos_to(&mut self, target: usize) {
+        let Some(pos) = self.pos.filter(|&pos| pos < self.inner.len()) else {
+            return;
+        };
+
+        if pos > target {
+            self.inner.rotate_left(pos - target);
+        } else {
+            self.inner.rotate_right(target - pos);
+        }
+
+        self.pos = Some(target);
+    }
+
+    /// Peek the next `count` elements after the cursor, not including the
+    /// pointed element.
+    ///
+    /// Elements are returned in forward order, wrapping around the end of the
+    /// underlying vector. If `count` exceeds the length of the vector, the
+    /// elements repeat. As with [`Self::peek()`], a cursor pointing to [`None`]
+    /// peeks from the first element. For an empty vector an empty [`Vec`] is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// assert_eq!(cycle_cursor.peek_ahead(3), vec![&4, &1, &2]);
+    /// ```
+    #[must_use]
+    pub fn peek_ahead(&self, count: usize) -> Vec<&T> {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return Vec::new();
+        }
+
+        let base = self.pos.unwrap_or(max_items - 1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        (1..=count)
+            .filter_map(|step| self.inner.get((base + step % max_items) % max_items))
+            .collect()
+    }
+
+    /// Peek the `count` elements before the cursor, not including the pointed
+    /// element.
+    ///
+    /// Elements are returned from the nearest to the furthest, i.e. in backward
+    /// order, wrapping around the start of the underlying vector. If `count`
+    /// exceeds the length of the vector, the elements repeat. For an empty
+    /// vector an empty [`Vec`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// assert_eq!(cycle_cursor.peek_behind(3), vec![&1, &4, &3]);
+    /// ```
+    #[must_use]
+    pub fn peek_behind(&self, count: usize) -> Vec<&T> {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return Vec::new();
+        }
+
+        let base = self.pos.unwrap_or(max_items - 1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        (1..=count)
+            .filter_map(|step| {
+                self.inner
+                    .get((base + max_items - step % max_items) % max_items)
+            })
+            .collect()
+    }
+
+    /// Return the number of leading elements in cursor order that satisfy the
+    /// predicate, starting at the pointed element (or the first element if
+    /// there is none).
+    ///
+    /// This mirrors [`slice::partition_point`] but anchored at the cursor, so
+    /// it works on a ring that is sorted starting from the cursor even if the
+    /// sort order wraps around the end of the underlying vector.
+    ///
+    /// The predicate is assumed to be monotonic over the traversal, i.e. it
+    /// returns `true` for all elements up to some point and `false` for all
+    /// elements after it. If it is not, the returned result is unspecified and
+    /// meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![5, 7, 1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// assert_eq!(
+    ///     cycle_cursor.partition_point_from_cursor(|item| *item < 4),
+    ///     3
+    /// );
+    /// ```
+    #[must_use]
+    pub fn partition_point_from_cursor<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        let max_items = self.inner.len();
+        let start = self.start_index();
+        let (mut low, mut high) = (0, max_items);
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            #[allow(clippy::integer_division_remainder_used)]
+            if self
+                .inner
+                .get((start + mid) % max_items)
+                .is_some_and(&mut pred)
+            {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        low
+    }
+
+    /// Consume the cursor into an infinite iterator of owned elements, cycling
+    /// forever starting from the pointed element (or the first element if there
+    /// is none).
+    ///
+    /// The iterator is empty if the underlying vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let items: Vec<_> = cycle_cursor.into_cycle_iter().take(5).collect();
+    /// assert_eq!(items, vec![2, 3, 1, 2, 3]);
+    /// ```
+    pub fn into_cycle_iter(mut self) -> impl Iterator<Item = T>
+    where
+        T: Clone,
+    {
+        let start = self.start_index();
+        self.inner.rotate_left(start);
+        self.inner.into_iter().cycle()
+    }
+
+    /// Store the current cursor position under a name, overwriting any
+    /// existing mark with the same name.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    /// cycle_cursor.mark("start");
+    ///
+    /// cycle_cursor.seek(3);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    ///
+    /// cycle_cursor.goto_mark("start").unwrap();
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn mark(&mut self, name: impl Into<String>) {
+        let _: Option<Option<usize>> = self.marks.insert(name.into(), self.pos);
+    }
+
+    /// Move the cursor to the position stored under a name by
+    /// [`Self::mark()`].
+    ///
+    /// # Errors
+    /// Returns [`CursorError::MarkNotFound`] if no mark exists with the name,
+    /// or [`CursorError::OutOfBounds`] if the underlying vector shrank below
+    /// the marked position. The cursor is left untouched on error.
+    pub fn goto_mark(&mut self, name: &str) -> Result<(), CursorError> {
+        let pos = *self.marks.get(name).ok_or(CursorError::MarkNotFound)?;
+
+        if pos.is_some_and(|index| index >= self.inner.len()) {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        self.land_on(pos);
+        Ok(())
+    }
+
+    /// Drop all marks that no longer lie within the bounds of the underlying
+    /// vector, e.g. after it has been truncated.
+    pub fn prune_marks(&mut self) {
+        let max_items = self.inner.len();
+        self.marks
+            .retain(|_, pos| pos.is_none_or(|index| index < max_items));
+    }
+
+    /// List the names of all stored marks in sorted order.
+    #[must_use]
+    pub fn list_marks(&self) -> Vec<&str> {
+        self.marks.keys().map(String::as_str).collect()
+    }
+
+    /// Split one pass in cursor order into chunks of exactly `size` elements,
+    /// starting at the pointed element (or the first element if there is none)
+    /// and wrapping around.
+    ///
+    /// This mirrors [`slice::chunks_exact`] on a ring. Returns an iterator over
+    /// the full chunks along with the remaining elements that do not fill a
+    /// whole chunk. The remainder is empty if the length of the underlying
+    /// vector is divisible by `size`.
+    ///
+    /// # Panics
+    /// Calling this method will panic if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let (chunks, remainder) = cycle_cursor.chunks_exact_cyclic(2);
+    /// assert_eq!(chunks.collect::<Vec<_>>(), vec![vec![&3, &4], vec![&5, &1]]);
+    /// assert_eq!(remainder, vec![&2]);
+    /// ```
+    pub fn chunks_exact_cyclic(&self, size: usize) -> (impl Iterator<Item = Vec<&T>>, Vec<&T>) {
+        assert!(size != 0, "Chunk size must be non-zero");
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let full_len = self.inner.len() - self.inner.len() % size;
+        let remainder = self.iter_from_cursor().skip(full_len).collect();
+
+        let mut items = self.iter_from_cursor().take(full_len);
+        let chunks = (0..full_len / size).map(move |_| items.by_ref().take(size).collect());
+
+        (chunks, remainder)
+    }
+
+    /// Swap two elements by absolute index, keeping the cursor on the same
+    /// element if it points to one of them.
+    ///
+    /// This is a cursor-aware version of [`slice::swap`], which stays reachable
+    /// through [`Deref`].
+    ///
+    /// # Errors
+    /// Returns [`CursorError::OutOfBounds`] if either index does not lie within
+    /// the bounds of the underlying vector. Nothing is swapped on error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.swap_indices(0, 2).unwrap();
+    /// assert_eq!(cycle_cursor.inner, vec![3, 2, 1, 4]);
+    /// assert_eq!(cycle_cursor.pos.unwrap(), 2);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    /// ```
+    pub fn swap_indices(&mut self, a: usize, b: usize) -> Result<(), CursorError> {
+        let max_items = self.inner.len();
+        if a >= max_items || b >= max_items {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        self.inner.swap(a, b);
+
+        if self.pos == Some(a) {
+            self.pos = Some(b);
+        } else if self.pos == Some(b) {
+            self.pos = Some(a);
+        }
+
+        Ok(())
+    }
+
+    /// Find the minimum element along with its signed cyclic offset from the
+    /// cursor, without moving the cursor.
+    ///
+    /// The offset has the smallest magnitude reaching the element, preferring
+    /// the forward direction when both are equal, and can be passed to
+    /// [`Self::peek()`] or [`Self::seek()`] to reach it. On ties the nearest
+    /// forward occurrence is returned. Returns [`None`] for an empty vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![3, 1, 4, 1, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let (offset, item) = cycle_cursor.min_with_offset().unwrap();
+    /// assert_eq!((offset, item), (1, &1));
+    /// assert_eq!(cycle_cursor.peek(offset).unwrap(), item);
+    /// ```
+    #[must_use]
+    pub fn min_with_offset(&self) -> Option<(isize, &T)>
+    where
+        T: Ord,
+    {
+        self.extremum_with_offset(Ordering::Less)
+    }
+
+    /// Find the maximum element along with its signed cyclic offset from the
+    /// cursor, without moving the cursor.
+    ///
+    /// See [`Self::min_with_offset()`] for how the offset and ties are
+    /// resolved. Returns [`None`] for an empty vector.
+    #[must_use]
+    pub fn max_with_offset(&self) -> Option<(isize, &T)>
+    where
+        T: Ord,
+    {
+        self.extremum_with_offset(Ordering::Greater)
+    }
+
+    /// Find the first element in forward order from the cursor that compares
+    /// as `ordering` to all others, along with its signed cyclic offset.
+    fn extremum_with_offset(&self, ordering: Ordering) -> Option<(isize, &T)>
+    where
+        T: Ord,
+    {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return None;
+        }
+
+        let base = self.pos.unwrap_or(max_items - 1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        (0..max_items)
+            .filter_map(|step| {
+                self.inner
+                    .get((base + step) % max_items)
+                    .map(|item| (step, item))
+            })
+            .reduce(|best, candidate| {
+                if candidate.1.cmp(best.1) == ordering {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .map(|(step, item)| (self.signed_offset(step), item))
+    }
+
+    /// Convert a number of forward steps into the signed cyclic offset of the
+    /// smallest magnitude, preferring the forward direction on ties.
+    #[allow(
+        clippy::as_conversions,
+        clippy::cast_possible_wrap,
+        reason = "vector lengths never exceed `isize::MAX`"
+    )]
+    fn signed_offset(&self, steps: usize) -> isize {
+        let max_items = self.inner.len();
+
+        if steps * 2 <= max_items {
+            steps as isize
+        } else {
+            -((max_items - steps) as isize)
+        }
+    }
+
+    /// Return the current cursor position if it points to an element.
+    ///
+    /// Unlike reading [`Self::pos`] directly, this returns [`None`] instead of
+    /// a stale position if the underlying vector was shrunk below it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_prev();
+    /// assert_eq!(cycle_cursor.current_index(), Some(3));
+    ///
+    /// cycle_cursor.truncate(2);
+    /// assert_eq!(cycle_cursor.current_index(), None);
+    /// ```
+    #[must_use]
+    pub fn current_index(&self) -> Option<usize> {
+        self.pos.filter(|&pos| pos < self.inner.len())
+    }
+
+    /// Check whether the currently pointed element equals `value`.
+    ///
+    /// Returns `false` when the cursor points to [`None`] or is stale, instead
+    /// of panicking like [`Self::get()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert!(!cycle_cursor.current_is(&1));
+    ///
+    /// cycle_cursor.cycle_next();
+    /// assert!(cycle_cursor.current_is(&1));
+    /// ```
+    #[must_use]
+    pub fn current_is(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.current_index()
+            .and_then(|index| self.inner.get(index))
+            .is_some_and(|item| item == value)
+    }
+
+    /// Collect the signed cyclic offsets from the cursor of every element that
+    /// satisfies the predicate.
+    ///
+    /// Each offset has the smallest magnitude reaching its element and can be
+    /// passed to [`Self::peek()`] or [`Self::seek()`]. Offsets are sorted by
+    /// magnitude, with the forward (positive) offset first when a forward and
+    /// a backward match are equally far. For an empty vector or when nothing
+    /// matches an empty [`Vec`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 1, 3, 1, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(4);
+    ///
+    /// let offsets = cycle_cursor.offsets_matching(|item| *item == 1);
+    /// assert_eq!(offsets, vec![1, -1, 3]);
+    /// ```
+    #[must_use]
+    pub fn offsets_matching<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Vec<isize> {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return Vec::new();
+        }
+
+        let base = self.pos.unwrap_or(max_items - 1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let mut offsets: Vec<isize> = (0..max_items)
+            .filter(|step| {
+                self.inner
+                    .get((base + step) % max_items)
+                    .is_some_and(&mut pred)
+            })
+            .map(|step| self.signed_offset(step))
+            .collect();
+
+        offsets.sort_by_key(|offset| (offset.unsigned_abs(), offset.is_negative()));
+        offsets
+    }
+
+    /// Rotate the underlying vector so the pointed element ends up at absolute
+    /// index `target`, keeping [`Self::get()`] invariant.
+    ///
+    /// This generalizes [`Self::rotate_to_center()`] to any target index.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::OutOfBounds`] if `target` does not lie within the
+    /// bounds of the underlying vector, or if the cursor points to [`None`] or
+    /// is stale. Nothing is rotated on error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.rotate_cursor_to_index(3).unwrap();
+    /// assert_eq!(cycle_cursor.inner, vec![2, 3, 4, 1]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    /// ```
+    pub fn rotate_cursor_to_index(&mut self, target: usize) -> Result<(), CursorError> {
+        if target >= self.inner.len() || self.current_index().is_none() {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        self.rotate_pos_to(target);
+        Ok(())
+    }
+
+    /// Remove and return all elements that satisfy the predicate, keeping the
+    /// cursor on its element.
+    ///
+    /// If the pointed element is removed, the cursor moves to the next
+    /// surviving element, wrapping around to the first survivor if needed. If
+    /// every element is removed the cursor is reset to [`None`]. Removed
+    /// elements are returned in their original order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let evens = cycle_cursor.extract_if_cursor(|item| item % 2 == 0);
+    /// assert_eq!(evens, vec![2, 4]);
+    /// assert_eq!(cycle_cursor.inner, vec![1, 3, 5]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &3);
+    /// ```
+    pub fn extract_if_cursor<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        self.extract_relocating(|item| pred(item))
+    }
+
+    /// Remove and return all elements for which `remove` returns `true`,
+    /// relocating the cursor to its element or the next survivor.
+    fn extract_relocating<F: FnMut(&mut T) -> bool>(&mut self, mut remove: F) -> Vec<T> {
+        let removed: Vec<bool> = self.inner.iter_mut().map(&mut remove).collect();
+
+        self.pos = self.current_index().and_then(|pos| {
+            let kept_before = removed
+                .iter()
+                .take(pos)
+                .filter(|&&is_removed| !is_removed)
+                .count();
+            let kept_after = removed.iter().skip(pos).any(|&is_removed| !is_removed);
+
+            if kept_after {
+                Some(kept_before)
+            } else {
+                (kept_before > 0).then_some(0)
+            }
+        });
+
+        let mut flags = removed.into_iter();
+        let (extracted, kept) = self
+            .inner
+            .drain(..)
+            .partition(|_| flags.next().unwrap_or(false));
+        self.inner = kept;
+        extracted
+    }
+
+    /// Preview the cursor positions after applying a sequence of
+    /// [`Self::seek()`] moves, without moving the cursor.
+    ///
+    /// Starting from the current position, each signed move is applied in
+    /// order with the same wrap behavior as [`Self::seek()`], and the resulting
+    /// position after every move is returned. For an empty vector every
+    /// position is [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// let positions = cycle_cursor.simulate(&[1, 2, -4, -1]);
+    /// assert_eq!(positions, vec![Some(0), Some(2), Some(2), Some(1)]);
+    /// assert_eq!(cycle_cursor.pos, None);
+    /// ```
+    #[must_use]
+    pub fn simulate(&self, moves: &[isize]) -> Vec<Option<usize>> {
+        moves
+            .iter()
+            .scan(self.pos, |pos, &seek_distance| {
+                *pos = self.seek_from(*pos, seek_distance);
+                Some(*pos)
+            })
+            .collect()
+    }
+
+    /// Capture the current cursor position as a [`CursorBookmark`], to later
+    /// measure movement with [`Self::steps_since()`].
+    #[must_use]
+    pub fn bookmark(&self) -> CursorBookmark {
+        CursorBookmark {
+            pos: self.pos,
+            len: self.inner.len(),
+        }
+    }
+
+    /// Return the signed cyclic distance the cursor moved since a bookmark.
+    ///
+    /// The distance has the smallest magnitude, preferring the forward
+    /// direction when both are equal. Returns [`None`] if either the bookmarked
+    /// or the current position is [`None`] or stale, or if the length of the
+    /// underlying vector changed since the bookmark. The distance is only
+    /// meaningful as long as the length has not changed in the meantime.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    /// let bookmark = cycle_cursor.bookmark();
+    ///
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(cycle_cursor.steps_since(&bookmark), Some(2));
+    ///
+    /// cycle_cursor.seek(-4);
+    /// assert_eq!(cycle_cursor.steps_since(&bookmark), Some(-2));
+    /// ```
+    #[must_use]
+    pub fn steps_since(&self, bookmark: &CursorBookmark) -> Option<isize> {
+        let max_items = self.inner.len();
+        if bookmark.len != max_items {
+            return None;
+        }
+
+        let from = bookmark.pos.filter(|&pos| pos < max_items)?;
+        let to = self.current_index()?;
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let steps = (to + max_items - from) % max_items;
+        Some(self.signed_offset(steps))
+    }
+
+    /// Map every element to an iterable and flatten the results, over one pass
+    /// in cursor order starting at the pointed element (or the first element
+    /// if there is none).
+    ///
+    /// The iterator is empty if the underlying vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_prev();
+    ///
+    /// let items: Vec<_> = cycle_cursor
+    ///     .flat_map_from_cursor(|item| [*item, item * 10])
+    ///     .collect();
+    /// assert_eq!(items, vec![3, 30, 1, 10, 2, 20]);
+    /// ```
+    pub fn flat_map_from_cursor<U, I: IntoIterator<Item = U>, F: FnMut(&T) -> I>(
+        &self,
+        f: F,
+    ) -> impl Iterator<Item = U> + use<'_, T, U, I, F> {
+        self.iter_from_cursor().flat_map(f)
+    }
+
+    /// Enable or disable autofix of stale positions in [`Self::get()`].
+    ///
+    /// When enabled, [`Self::get()`] no longer panics if the underlying vector
+    /// was shrunk below the cursor position. It instead behaves as if the
+    /// cursor was moved back to the nearest valid index, i.e. the last
+    /// element, and returns that element. The position itself is left as is.
+    /// Autofix is disabled by default.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.set_autofix(true);
+    /// cycle_cursor.cycle_prev();
+    ///
+    /// cycle_cursor.truncate(2);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn set_autofix(&mut self, enabled: bool) {
+        self.autofix = enabled;
+    }
+
+    /// Rotate the elements within an absolute index range to the left by
+    /// `mid`, keeping the cursor on its element if it lies within the range.
+    ///
+    /// This behaves like calling [`slice::rotate_left`] on the sub-slice, so
+    /// the element at `range.start + mid` becomes the first of the range.
+    /// Elements outside the range are not moved.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::OutOfBounds`] if the range does not lie within
+    /// the bounds of the underlying vector or if `mid` is greater than the
+    /// length of the range. Nothing is rotated on error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// cycle_cursor.rotate_subrange(1..5, 1).unwrap();
+    /// assert_eq!(cycle_cursor.inner, vec![1, 3, 4, 5, 2, 6]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn rotate_subrange(&mut self, range: Range<usize>, mid: usize) -> Result<(), CursorError> {
+        let sub_slice = self
+            .inner
+            .get_mut(range.clone())
+            .ok_or(CursorError::OutOfBounds)?;
+        let range_len = sub_slice.len();
+        if mid > range_len {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        sub_slice.rotate_left(mid);
+
+        if let Some(pos) = self.pos.filter(|pos| range.contains(pos)) {
+            #[allow(clippy::integer_division_remainder_used)]
+            let new_pos = range.start + (pos - range.start + range_len - mid) % range_len;
+            self.pos = Some(new_pos);
+        }
+
+        Ok(())
+    }
+
+    /// Replace every element equal to `from` with a clone of `to`, returning
+    /// the number of replaced elements.
+    ///
+    /// Since the length is unchanged, the cursor stays valid and keeps
+    /// pointing to the same index. The pointed element may therefore change
+    /// value if it was among those replaced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 1, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert_eq!(cycle_cursor.replace_all(&1, &0), 2);
+    /// assert_eq!(cycle_cursor.inner, vec![0, 2, 0, 3]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &0);
+    /// ```
+    pub fn replace_all(&mut self, from: &T, to: &T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut replaced = 0;
+
+        for item in self.inner.iter_mut().filter(|item| *item == from) {
+            item.clone_from(to);
+            replaced += 1;
+        }
+
+        replaced
+    }
+
+    /// Generate a round-robin schedule pairing every element with every other
+    /// element exactly once, using the cursor as the fixed pivot.
+    ///
+    /// Pairs are generated with the classic circle method. The pointed element
+    /// (or the first element if there is none) stays fixed while the others,
+    /// in cursor order, rotate around it by one position each round. In each
+    /// round the pivot is paired with the first rotating element, and the
+    /// remaining rotating elements are paired from the outside in. For an odd
+    /// number of elements, a bye is added and pairs with it are skipped.
+    ///
+    /// Pairs are yielded round by round, each round holding `len / 2` pairs,
+    /// for a total of `len * (len - 1) / 2` pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// let pairs: Vec<_> = cycle_cursor.round_robin_pairs().collect();
+    /// assert_eq!(pairs, vec![
+    ///     (&1, &2),
+    ///     (&3, &4),
+    ///     (&1, &3),
+    ///     (&4, &2),
+    ///     (&1, &4),
+    ///     (&2, &3),
+    /// ]);
+    /// ```
+    pub fn round_robin_pairs(&self) -> impl Iterator<Item = (&T, &T)> {
+        let mut slots: Vec<Option<&T>> = self.iter_from_cursor().map(Some).collect();
+        #[allow(clippy::integer_division_remainder_used)]
+        let is_odd = slots.len() % 2 != 0;
+        if is_odd {
+            slots.push(None);
+        }
+
+        let rotating = slots.len().saturating_sub(1);
+        let mut pairs = Vec::new();
+
+        for round in 0..rotating {
+            for step in 0..slots.len() / 2 {
+                #[allow(clippy::integer_division_remainder_used)]
+                let (first, second) = if step == 0 {
+                    (0, 1 + round)
+                } else {
+                    (
+                        1 + (round + step) % rotating,
+                        1 + (round + rotating - step) % rotating,
+                    )
+                };
+
+                if let (Some(&Some(first)), Some(&Some(second))) =
+                    (slots.get(first), slots.get(second))
+                {
+                    pairs.push((first, second));
+                }
+            }
+        }
+
+        pairs.into_iter()
+    }
+
+    /// Consume the cursor into a [`VecDeque`] holding the elements in order,
+    /// along with the cursor position.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::collections::VecDeque;
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_prev();
+    ///
+    /// let (deque, pos) = cycle_cursor.into_vecdeque();
+    /// assert_eq!(deque, VecDeque::from([1, 2, 3]));
+    /// assert_eq!(pos, Some(2));
+    /// ```
+    #[must_use]
+    pub fn into_vecdeque(self) -> (VecDeque<T>, Option<usize>) {
+        (VecDeque::from(self.inner), self.pos)
+    }
+
+    /// Create a cursor from a [`VecDeque`], preserving the order of elements.
+    ///
+    /// The cursor points to [`None`], as with [`From`].
+    #[must_use]
+    pub fn from_vecdeque(dq: VecDeque<T>) -> Self {
+        Self::from_parts(Vec::from(dq), None)
+    }
+
+    /// Iterate over all cyclic windows of `size` elements, over one pass in
+    /// cursor order.
+    ///
+    /// One window starts at every element, beginning with the pointed element
+    /// (or the first element if there is none), and wraps around the end of
+    /// the underlying vector, so there are exactly `len` windows. If `size`
+    /// exceeds the length of the vector, elements repeat within a window.
+    /// Nothing is yielded if `size` is zero or the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let windows: Vec<_> = cycle_cursor.windows_cyclic(2).collect();
+    /// assert_eq!(windows, vec![vec![&2, &3], vec![&3, &1], vec![&1, &2]]);
+    /// ```
+    pub fn windows_cyclic(&self, size: usize) -> impl Iterator<Item = Vec<&T>> {
+        let max_items = self.inner.len();
+        let start = self.start_index();
+        let window_count = if size == 0 { 0 } else { max_items };
+
+        (0..window_count).map(move |offset| {
+            #[allow(clippy::integer_division_remainder_used)]
+            (0..size)
+                .filter_map(|step| self.inner.get((start + offset + step) % max_items))
+                .collect()
+        })
+    }
+
+    /// Iterate over all cyclic windows of `size` elements as owned clones, over
+    /// one pass in cursor order.
+    ///
+    /// This behaves exactly like [`Self::windows_cyclic()`], but the windows
+    /// can be stored or sent beyond the borrow of the cursor.
+    pub fn windows_cyclic_owned(&self, size: usize) -> impl Iterator<Item = Vec<T>> + '_
+    where
+        T: Clone,
+    {
+        self.windows_cyclic(size)
+            .map(|window| window.into_iter().cloned().collect())
+    }
+
+    /// Return the fundamental period of the ring, i.e. the smallest shift `p`
+    /// such that rotating the underlying vector by `p` leaves it unchanged.
+    ///
+    /// A ring without any smaller repetition, such as one of distinct
+    /// elements, has a period equal to its length. Returns [`None`] for an
+    /// empty vector. Only divisors of the length are checked, for a complexity
+    /// of `O(len * divisors(len))`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let cycle_cursor = CycleCursor::from(vec![1, 2, 3, 1, 2, 3]);
+    /// assert_eq!(cycle_cursor.cycle_period(), Some(3));
+    ///
+    /// let cycle_cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(cycle_cursor.cycle_period(), Some(4));
+    /// ```
+    #[must_use]
+    pub fn cycle_period(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let max_items = self.inner.len();
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let mut divisors = (1..=max_items).filter(|period| max_items % period == 0);
+
+        divisors.find(|period| {
+            self.inner
+                .iter()
+                .zip(self.inner.iter().cycle().skip(*period))
+                .all(|(item, shifted)| item == shifted)
+        })
+    }
+
+    /// Move the cursor by a fractional offset, carrying sub-step movement over
+    /// to later calls.
+    ///
+    /// The offset is added to an internal accumulator, the whole part of which
+    /// is applied as a [`Self::seek()`]. The remaining fraction is kept for the
+    /// next call and returned. This maps smooth input, e.g. from a trackpad,
+    /// onto discrete cursor steps without losing sub-step motion.
+    ///
+    /// The whole part is truncated towards zero, so the cursor only moves once
+    /// a full step accumulates in either direction. In particular an
+    /// accumulated `0.5` does not round up to a step. A NaN or infinite offset
+    /// is ignored, leaving the cursor and the remainder unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert_eq!(cycle_cursor.seek_fractional(0.75), 0.75);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    ///
+    /// assert_eq!(cycle_cursor.seek_fractional(0.5), 0.25);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn seek_fractional(&mut self, delta: f64) -> f64 {
+        if !delta.is_finite() {
+            return self.fractional_seek;
+        }
+
+        let accumulated = self.fractional_seek + delta;
+        let whole = accumulated.trunc();
+
+        // Whole turns around the ring are dropped so the step count fits.
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            clippy::modulo_arithmetic,
+            reason = "the step count is reduced below the length before the cast"
+        )]
+        let steps = (whole % self.inner.len().max(1) as f64) as isize;
+        self.seek(steps);
+        self.fractional_seek = accumulated - whole;
+        self.fractional_seek
+    }
+
+    /// Apply a sequence of [`Self::seek()`] moves with a single update.
+    ///
+    /// Since moves are additive modulo the length, the moves are summed first
+    /// and the cursor is moved once, which is cheaper than seeking once per
+    /// move. The result is the same as calling [`Self::seek()`] for each move
+    /// in turn, including a cursor pointing to [`None`] seeking from the last
+    /// index.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// cycle_cursor.apply_moves(&[3, -2, 5]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn apply_moves(&mut self, moves: &[isize]) {
+        let max_items = self.inner.len();
+        if max_items == 0 || moves.is_empty() {
+            return;
+        }
+
+        #[allow(clippy::integer_division_remainder_used)]
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_wrap,
+            reason = "vector lengths never exceed `isize::MAX`"
+        )]
+        let net_distance = moves.iter().fold(0, |net_distance, seek_distance| {
+            (net_distance + seek_distance.rem_euclid(max_items as isize) as usize) % max_items
+        });
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let pos = (self.pos.unwrap_or(max_items - 1) + net_distance) % max_items;
+        self.land_on(Some(pos));
+    }
+
+    /// Split the underlying vector at the cursor into two contiguous spans,
+    /// `inner[pos..]` and `inner[..pos]`.
+    ///
+    /// Chaining the two spans yields the ring in cursor order without any
+    /// allocation. If the cursor points to [`None`] or is stale, the first span
+    /// holds the whole vector and the second one is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let (tail, head) = cycle_cursor.spans_from_cursor();
+    /// assert_eq!(tail, &[3, 4]);
+    /// assert_eq!(head, &[1, 2]);
+    /// ```
+    #[must_use]
+    pub fn spans_from_cursor(&self) -> (&[T], &[T]) {
+        let (head, tail) = self.inner.split_at(self.start_index());
+        (tail, head)
+    }
+
+    /// Retain only the elements for which the closure returns `true`, passing
+    /// a mutable reference so elements can be edited in the same pass.
+    ///
+    /// This mirrors [`Vec::retain_mut`] while keeping the cursor on its
+    /// element. If the pointed element is dropped, the cursor moves to the
+    /// next surviving element as in [`Self::extract_if_cursor()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// cycle_cursor.retain_mut_cursor(|item| {
+    ///     *item *= 10;
+    ///     *item != 20
+    /// });
+    /// assert_eq!(cycle_cursor.inner, vec![10, 30, 40]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &30);
+    /// ```
+    pub fn retain_mut_cursor<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        drop(self.extract_relocating(|item| !f(item)));
+    }
+
+    /// Capture a cheap fingerprint of the cursor state, to later detect
+    /// changes with [`Self::changed_since()`] without deep-cloning.
+    ///
+    /// The fingerprint holds the length, the cursor position and a hash of
+    /// the underlying vector.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint
+    where
+        T: Hash,
+    {
+        Checkpoint {
+            len: self.inner.len(),
+            pos: self.pos,
+            hash: self.inner_hasher().finish(),
+        }
+    }
+
+    /// Check whether the data or the cursor position changed since a
+    /// checkpoint.
+    ///
+    /// Data changes are detected by hash, so a change that results in a hash
+    /// collision is missed and reported as unchanged. This is rare enough to be
+    /// acceptable for change detection, e.g. in a render loop.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// let checkpoint = cycle_cursor.checkpoint();
+    /// assert!(!cycle_cursor.changed_since(&checkpoint));
+    ///
+    /// cycle_cursor.push(5);
+    /// assert!(cycle_cursor.changed_since(&checkpoint));
+    /// ```
+    #[must_use]
+    pub fn changed_since(&self, cp: &Checkpoint) -> bool
+    where
+        T: Hash,
+    {
+        self.checkpoint() != *cp
+    }
+
+    /// Iterate over one pass in cursor order with `sep` yielded between every
+    /// two elements.
+    ///
+    /// No separator is yielded before the first or after the last element, so
+    /// an empty vector yields nothing and a single element vector yields only
+    /// that element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let items: Vec<_> = cycle_cursor.intersperse_from_cursor(&0).collect();
+    /// assert_eq!(items, vec![&2, &0, &3, &0, &1]);
+    /// ```
+    pub fn intersperse_from_cursor<'a>(&'a self, sep: &'a T) -> impl Iterator<Item = &'a T> {
+        self.iter_from_cursor()
+            .enumerate()
+            .flat_map(move |(step, item)| (step > 0).then_some(sep).into_iter().chain([item]))
+    }
+
+    /// Move every element by `by` positions around the ring while keeping the
+    /// cursor position fixed.
+    ///
+    /// A positive shift moves elements forward (towards higher indices) and a
+    /// negative shift moves them backward, wrapping around. Unlike
+    /// [`Self::rotate_to_center()`] and similar rotations, which keep
+    /// [`Self::get()`] invariant, the cursor acts as a fixed viewport here, so
+    /// afterwards it points to the element that was `by` positions behind it.
+    ///
+    /// For the same sign, [`Self::scroll()`] moves the elements the opposite
+    /// way, as it pairs them with a cursor moving forward. A positive `by`
+    /// here is a [`slice::rotate_right()`] of the underlying vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.shift_values(1);
+    /// assert_eq!(cycle_cursor.inner, vec![4, 1, 2, 3]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &4);
+    /// ```
+    pub fn shift_values(&mut self, by: isize) {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return;
+        }
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let shift = by.unsigned_abs() % max_items;
+
+        if by < 0 {
+            self.inner.rotate_left(shift);
+        } else {
+            self.inner.rotate_right(shift);
+        }
+    }
+
+    /// Mutably access the currently pointed element without panicking.
+    ///
+    /// Returns `Ok(None)` if the cursor points to [`None`].
+    ///
+    /// # Errors
+    /// Returns [`CursorError::StalePosition`] if the underlying vector has been
+    /// altered and the position marker drops below the maximum length of the
+    /// vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::{CursorError, CycleCursor};
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_prev();
+    ///
+    /// *cycle_cursor.try_get_mut().unwrap().unwrap() = 5;
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    ///
+    /// cycle_cursor.truncate(2);
+    /// assert_eq!(cycle_cursor.try_get_mut(), Err(CursorError::StalePosition));
+    /// ```
+    pub fn try_get_mut(&mut self) -> Result<Option<&mut T>, CursorError> {
+        let Some(pos) = self.pos else {
+            return Ok(None);
+        };
+
+        self.inner
+            .get_mut(pos)
+            .map(Some)
+            .ok_or(CursorError::StalePosition)
+    }
+
+    /// Mutably access currently pointed element from [`Self`]. Unlike
+    /// [`slice::get_mut`] of the underlying [`Vec`], no index is taken.
+    ///
+    /// As with [`Self::get()`], a stale position is clamped to the last element
+    /// if autofix is enabled through [`Self::set_autofix()`].
+    ///
+    /// # Panics
+    /// Calling this method will panic if the underlying vector has been altered
+    /// and the position marker drops below the maximum length of the vector,
+    /// unless autofix is enabled. See [`Self::try_get_mut()`] for a
+    /// non-panicking alternative.
+    pub fn get_current_mut(&mut self) -> Option<&mut T> {
+        if self.autofix && self.pos.is_some() && self.current_index().is_none() {
+            return self.inner.last_mut();
+        }
+
+        self.try_get_mut().unwrap_or_else(|_| {
+            panic!(
+                "Undefined behavior: Underlying vec was modified. Run cycle_next or cycle_prev to \
+                 return to standard."
+            )
+        })
+    }
+
+    /// Search backward for an element satisfying the predicate, moving the
+    /// cursor to it.
+    ///
+    /// The search starts at the element before the cursor and wraps around at
+    /// most once, so the pointed element itself is checked last. If the cursor
+    /// points to [`None`], the search starts at the last element, as with
+    /// [`Self::cycle_prev()`]. Returns the matched element, or [`None`] leaving
+    /// the cursor untouched if nothing matches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// assert_eq!(cycle_cursor.find_prev_move(|item| item % 2 == 0), Some(&4));
+    /// assert_eq!(cycle_cursor.pos.unwrap(), 3);
+    /// ```
+    pub fn find_prev_move<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<&T> {
+        let max_items = self.inner.len();
+        let base = self.pos.unwrap_or(max_items);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let index = (1..=max_items)
+            .map(|step| (base + max_items - step) % max_items)
+            .find(|&index| self.inner.get(index).is_some_and(&mut pred))?;
+
+        self.land_on(Some(index));
+        self.inner.get(index)
+    }
+
+    /// Check whether both cursors hold the same elements with the same
+    /// multiplicities, ignoring their order and the cursor positions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let cycle_cursor = CycleCursor::from(vec![1, 2, 2, 3]);
+    /// let other = CycleCursor::from(vec![2, 3, 1, 2]);
+    ///
+    /// assert!(cycle_cursor.same_multiset(&other));
+    /// assert!(!cycle_cursor.same_multiset(&CycleCursor::from(vec![1, 2, 3, 3])));
+    /// ```
+    #[must_use]
+    pub fn same_multiset(&self, other: &Self) -> bool
+    where
+        T: Ord + Clone,
+    {
+        if self.inner.len() != other.inner.len() {
+            return false;
+        }
+
+        let mut own = self.inner.clone();
+        let mut others = other.inner.clone();
+        own.sort_unstable();
+        others.sort_unstable();
+
+        own == others
+    }
+
+    /// Seek the cursor by an offset without wrapping, clamping at the first
+    /// and last elements.
+    ///
+    /// Returns the number of steps actually moved, which is smaller in
+    /// magnitude than `offset` when a boundary was hit. If the cursor points to
+    /// [`None`] or is stale, movement starts from the first element. An empty
+    /// vector leaves the cursor untouched and returns `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.seek_clamped(3), 3);
+    /// assert_eq!(cycle_cursor.seek_clamped(3), 1);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    ///
+    /// assert_eq!(cycle_cursor.seek_clamped(-10), -4);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    /// ```
+    pub fn seek_clamped(&mut self, offset: isize) -> isize {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return 0;
+        }
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_wrap,
+            reason = "vector lengths never exceed `isize::MAX`"
+        )]
+        let (base, last_index) = (self.start_index() as isize, (max_items - 1) as isize);
+        let target = base.saturating_add(offset).clamp(0, last_index);
+        self.land_on(Some(target.unsigned_abs()));
+
+        target - base
+    }
+
+    /// Iterate in cursor order, starting at the pointed element (or the first
+    /// element if there is none), for as long as the predicate holds.
+    ///
+    /// The iteration covers at most one full pass, so it terminates after
+    /// `len` elements even if every element matches. The cursor is not moved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(4);
+    ///
+    /// let items: Vec<_> = cycle_cursor
+    ///     .take_while_from_cursor(|item| *item != 2)
+    ///     .collect();
+    /// assert_eq!(items, vec![&4, &5, &1]);
+    /// ```
+    pub fn take_while_from_cursor<F: FnMut(&T) -> bool>(
+        &self,
+        mut pred: F,
+    ) -> impl Iterator<Item = &T> {
+        self.iter_from_cursor().take_while(move |item| pred(item))
+    }
+
+    /// Start recording every position the cursor lands on through movement
+    /// methods such as [`Self::cycle_next()`], [`Self::seek()`] or
+    /// [`Self::goto_mark()`], keeping at most the `capacity` most recent ones.
+    ///
+    /// The current position, if any, is recorded as the first entry. Calling
+    /// this again restarts the recording with the new capacity, and a capacity
+    /// of `0` disables it. Recorded positions are plain indices, so resizing
+    /// the underlying vector may invalidate them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.enable_history(3);
+    ///
+    /// cycle_cursor.cycle_next();
+    /// cycle_cursor.seek(2);
+    /// cycle_cursor.seek(-1);
+    /// cycle_cursor.seek(3);
+    /// assert_eq!(cycle_cursor.history(), &[2, 1, 4]);
+    /// ```
+    pub fn enable_history(&mut self, capacity: usize) {
+        self.history.clear();
+        self.history_capacity = capacity;
+
+        if capacity > 0 {
+            self.history.extend(self.current_index());
+        }
+    }
+
+    /// Positions recorded since [`Self::enable_history()`], oldest first. The
+    /// last entry is the position the cursor most recently landed on.
+    ///
+    /// The history is a bounded ring buffer, so recording a position at full
+    /// capacity evicts the oldest one in constant time. Reading it takes
+    /// `&mut self` as the buffer is rearranged into a contiguous slice.
+    #[must_use]
+    pub fn history(&mut self) -> &[usize] {
+        self.history.make_contiguous()
+    }
+
+    /// Go back to the previously visited position, like the back button of a
+    /// browser, and return it.
+    ///
+    /// The most recent entry is dropped from the history and the cursor moves
+    /// to the entry before it. Entries that are no longer valid indices, for
+    /// example after the underlying vector shrank, are skipped and dropped.
+    /// Returns [`None`] and leaves the cursor untouched if no earlier valid
+    /// position is recorded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.enable_history(8);
+    ///
+    /// cycle_cursor.seek(2);
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(cycle_cursor.back(), Some(1));
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// assert_eq!(cycle_cursor.back(), None);
+    /// ```
+    pub fn back(&mut self) -> Option<usize> {
+        let max_items = self.inner.len();
+        let latest = self.history.pop_back()?;
+
+        while let Some(&pos) = self.history.back() {
+            if pos < max_items {
+                self.move_to(Some(pos));
+                return Some(pos);
+            }
+            let _: Option<usize> = self.history.pop_back();
+        }
+
+        self.history.push_back(latest);
+        None
+    }
+
+    /// Point the cursor to a uniformly random element, leaving the order of
+    /// the underlying vector untouched.
+    ///
+    /// This is useful to spread load across a ring instead of always starting
+    /// at the first element. An empty vector sets the cursor to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    /// let mut rng = StdRng::seed_from_u64(7);
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.random_start(&mut rng);
+    ///
+    /// assert!(cycle_cursor.get().is_some());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_start<R: rand::Rng>(&mut self, rng: &mut R) {
+        let max_items = self.inner.len();
+        self.land_on((max_items > 0).then(|| rng.random_range(0..max_items)));
+    }
+
+    /// Get mutable references to the previous, current and next elements
+    /// around the cursor.
+    ///
+    /// The three references must point to distinct elements, so this returns
+    /// [`None`] when the vector holds fewer than three elements, as well as
+    /// when the cursor points to [`None`] or is stale.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// if let Some((prev, current, next)) = cycle_cursor.neighbors_mut() {
+    ///     *current = *prev + *next;
+    /// }
+    /// assert_eq!(cycle_cursor.inner, vec![6, 2, 3, 4]);
+    /// ```
+    pub fn neighbors_mut(&mut self) -> Option<(&mut T, &mut T, &mut T)> {
+        if self.inner.len() < 3 {
+            return None;
+        }
+
+        let pos = self.current_index()?;
+        let (head, rest) = self.inner.split_at_mut(pos);
+        let (current, tail) = rest.split_first_mut()?;
+
+        // At either end, both neighbors lie on the same side of the cursor
+        let (prev, next) = if head.is_empty() {
+            let (next, rest) = tail.split_first_mut()?;
+            (rest.last_mut()?, next)
+        } else if tail.is_empty() {
+            let (next, rest) = head.split_first_mut()?;
+            (rest.last_mut()?, next)
+        } else {
+            (head.last_mut()?, tail.first_mut()?)
+        };
+
+        Some((prev, current, next))
+    }
+
+    /// Move the cursor to the element at a relative position between the
+    /// first (`0.0`) and last (`1.0`) elements, like dragging a scrollbar.
+    ///
+    /// The target index is `frac * (len - 1)` rounded to the nearest index,
+    /// with exact midpoints rounded up to the later element. `frac` is clamped
+    /// to `[0.0, 1.0]` and a `NaN` is treated as `0.0`. On an empty vector the
+    /// cursor is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// cycle_cursor.seek_to_fraction(0.5);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &3);
+    ///
+    /// cycle_cursor.seek_to_fraction(2.0);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    /// ```
+    pub fn seek_to_fraction(&mut self, frac: f64) {
+        let Some(last_index) = self.inner.len().checked_sub(1) else {
+            return;
+        };
+
+        let frac = if frac.is_nan() {
+            0.0
+        } else {
+            frac.clamp(0.0, 1.0)
+        };
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            clippy::cast_sign_loss,
+            reason = "the fraction is clamped to [0, 1] so the rounded index stays in bounds"
+        )]
+        let pos = (frac * last_index as f64).round() as usize;
+        self.land_on(Some(pos));
+    }
+
+    /// Iterate over one pass of this cursor followed by one pass of `other`,
+    /// each in its own cursor order, without copying any elements.
+    ///
+    /// Each pass starts at the pointed element of its cursor, or the first
+    /// element if there is none, as in [`Self::spans_from_cursor()`]. An empty
+    /// side contributes no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let mut cycle_cursor = CycleCursor::from(vec![1, 2, 3]);
+    /// let mut other = CycleCursor::from(vec![4, 5]);
+    /// cycle_cursor.seek(2);
+    /// other.seek(2);
+    ///
+    /// let items: Vec<_> = cycle_cursor.chain_iter(&other).collect();
+    /// assert_eq!(items, vec![&2, &3, &1, &5, &4]);
+    /// ```
+    pub fn chain_iter<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> {
+        self.iter_from_cursor().chain(other.iter_from_cursor())
+    }
+
+    /// Move the cursor to the candidate index the fewest cyclic steps away,
+    /// returning the chosen index.
+    ///
+    /// Steps are counted in either direction from the pointed element, or from
+    /// the first element if there is none. When a forward and a backward
+    /// candidate are equally far, the forward one wins. Out of bounds
+    /// candidates are ignored, and [`None`] is returned with the cursor left
+    /// untouched if no valid candidate remains.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// assert_eq!(cycle_cursor.seek_to_nearest(&[4, 7]), Some(7));
+    /// assert_eq!(cycle_cursor.seek_to_nearest(&[1, 5]), Some(1));
+    /// ```
+    pub fn seek_to_nearest(&mut self, candidates: &[usize]) -> Option<usize> {
+        let max_items = self.inner.len();
+        let pos = self.start_index();
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let nearest = candidates
+            .iter()
+            .copied()
+            .filter(|&candidate| candidate < max_items)
+            .min_by_key(|&candidate| {
+                let offset = self.signed_offset((candidate + max_items - pos) % max_items);
+                (offset.unsigned_abs(), offset < 0)
+            })?;
+
+        self.land_on(Some(nearest));
+        Some(nearest)
+    }
+
+    /// Apply `f` to every cyclic window of `size` elements and collect the
+    /// results, e.g. to compute moving averages around a closed loop.
+    ///
+    /// Windows are the same as those of [`Self::windows_cyclic()`], so an
+    /// empty [`Vec`] is returned if `size` is `0` or the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// let sums = cycle_cursor.map_windows_cyclic(2, |window| window.iter().copied().sum::<i32>());
+    /// assert_eq!(sums, vec![3, 5, 7, 5]);
+    /// ```
+    #[must_use]
+    pub fn map_windows_cyclic<B, F: FnMut(&[&T]) -> B>(&self, size: usize, mut f: F) -> Vec<B> {
+        self.windows_cyclic(size).map(|window| f(&window)).collect()
+    }
+
+    /// Serialize the cursor into a compact JSON object, without depending on
+    /// `serde`.
+    ///
+    /// The format is `{"inner":[a,b,...],"pos":n}`, where `pos` is the raw
+    /// cursor position, or `null` if the cursor points to [`None`]. Elements
+    /// are rendered with their [`Display`] implementation. Output that reads
+    /// as a JSON number or boolean is written verbatim, anything else is
+    /// written as a quoted and escaped JSON string, so the result is always
+    /// valid JSON. A string element such as `"42"` therefore comes out as the
+    /// number `42`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert_eq!(cycle_cursor.to_json(), r#"{"inner":[1,2,3],"pos":0}"#);
+    ///
+    /// let words = CycleCursor::from(vec!["a", "b\"c"]);
+    /// assert_eq!(words.to_json(), r#"{"inner":["a","b\"c"],"pos":null}"#);
+    /// ```
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn to_json(&self) -> String
+    where
+        T: Display,
+    {
+        let items: Vec<String> = self.inner.iter().map(Self::json_value).collect();
+        format!(
+            r#"{{"inner":[{}],{}}}"#,
+            items.join(","),
+            self.pos_json_field()
+        )
+    }
+
+    /// Serialize only the cursor position into a JSON object of the form
+    /// `{"pos":n}`, using `null` if the cursor points to [`None`], as in
+    /// [`Self::to_json()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert_eq!(cycle_cursor.pos_json(), r#"{"pos":null}"#);
+    ///
+    /// cycle_cursor.seek(3);
+    /// assert_eq!(cycle_cursor.pos_json(), r#"{"pos":2}"#);
+    /// ```
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn pos_json(&self) -> String {
+        format!("{{{}}}", self.pos_json_field())
+    }
+
+    /// JSON `"pos"` member shared by [`Self::to_json()`] and
+    /// [`Self::pos_json()`].
+    #[cfg(feature = "json")]
+    fn pos_json_field(&self) -> String {
+        self.pos.map_or_else(
+            || r#""pos":null"#.to_owned(),
+            |pos| format!(r#""pos":{pos}"#),
+        )
+    }
+
+    /// Render an element for [`Self::to_json()`], keeping numbers and booleans
+    /// verbatim and writing anything else as an escaped JSON string.
+    #[cfg(feature = "json")]
+    fn json_value(item: &T) -> String
+    where
+        T: Display,
+    {
+        use fmt::Write as _;
+
+        let text = item.to_string();
+        if text == "true" || text == "false" || Self::is_json_number(&text) {
+            return text;
+        }
+
+        let mut escaped = String::with_capacity(text.len() + 2);
+        escaped.push('"');
+        for ch in text.chars() {
+            match ch {
+                '"' => escaped.push_str(r#"\""#),
+                '\\' => escaped.push_str(r"\\"),
+                '\n' => escaped.push_str(r"\n"),
+                '\r' => escaped.push_str(r"\r"),
+                '\t' => escaped.push_str(r"\t"),
+                control if control < ' ' => {
+                    let _: fmt::Result = write!(escaped, r"\u{:04x}", u32::from(control));
+                }
+                other => escaped.push(other),
+            }
+        }
+        escaped.push('"');
+        escaped
+    }
+
+    /// Check whether `text` follows the JSON number grammar, which is stricter
+    /// than Rust float parsing and rejects forms like `+1`, `01`, `.5` or
+    /// `inf`.
+    #[cfg(feature = "json")]
+    fn is_json_number(text: &str) -> bool {
+        let is_digits =
+            |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+
+        let unsigned = text.strip_prefix('-').unwrap_or(text);
+        let (mantissa, exponent) = unsigned
+            .split_once(['e', 'E'])
+            .map_or((unsigned, None), |(mantissa, exponent)| {
+                (mantissa, Some(exponent))
+            });
+        let (integer, fraction) = mantissa
+            .split_once('.')
+            .map_or((mantissa, None), |(integer, fraction)| {
+                (integer, Some(fraction))
+            });
+
+        is_digits(integer)
+            && (integer == "0" || !integer.starts_with('0'))
+            && fraction.is_none_or(is_digits)
+            && exponent.is_none_or(|exponent| {
+                is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent))
+            })
+    }
+
+    /// Rotate the underlying vector to its lexicographically smallest rotation
+    /// and point the cursor to the first element.
+    ///
+    /// Rings that are rotations of each other end up with identical data,
+    /// which makes this the normalization step for comparing or deduplicating
+    /// necklaces. Every rotation is compared against the best one so far, which
+    /// takes `O(n²)` comparisons in the worst case, e.g. when all elements are
+    /// equal. An empty vector is left untouched with the cursor at [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![3, 1, 2, 1, 1];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.canonicalize();
+    ///
+    /// assert_eq!(cycle_cursor.inner, vec![1, 1, 3, 1, 2]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    /// ```
+    pub fn canonicalize(&mut self)
+    where
+        T: Ord,
+    {
+        if self.inner.is_empty() {
+            self.pos = None;
+            return;
+        }
+
+        let rotation = |start: usize| {
+            let (head, tail) = self.inner.split_at(start);
+            tail.iter().chain(head)
+        };
+        let smallest = (1..self.inner.len()).fold(0, |smallest, start| {
+            if rotation(start).lt(rotation(smallest)) {
+                start
+            } else {
+                smallest
+            }
+        });
+
+        self.inner.rotate_left(smallest);
+        self.pos = Some(0);
+    }
+
+    /// Iterate backward forever, cycling through the elements in reverse.
+    ///
+    /// The first yielded element is the pointed element (or the first element
+    /// if there is none), followed by the element before it, wrapping from the
+    /// first element to the last. This is the reverse counterpart of
+    /// [`Self::into_cycle_iter()`] and does not consume the cursor. The
+    /// iterator is empty if the underlying vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// let items: Vec<_> = cycle_cursor.iter_cycle_rev().take(4).collect();
+    /// assert_eq!(items, vec![&1, &3, &2, &1]);
+    /// ```
+    pub fn iter_cycle_rev(&self) -> impl Iterator<Item = &T> {
+        let split = (self.start_index() + 1).min(self.inner.len());
+        let (head, tail) = self.inner.split_at(split);
+        head.iter().rev().chain(tail.iter().rev()).cycle()
+    }
+
+    /// Access the element at an absolute index, returning [`None`] if it is
+    /// out of bounds.
+    ///
+    /// Unlike [`Self::get()`] and [`Self::peek()`], which are relative to the
+    /// cursor, this ignores the cursor position entirely. It is a
+    /// non-panicking alternative to indexing through [`Deref`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.at(1), Some(&2));
+    /// assert_eq!(cycle_cursor.at(3), None);
+    /// ```
+    #[must_use]
+    pub fn at(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    /// Mutably access the element at an absolute index, returning [`None`] if
+    /// it is out of bounds. See [`Self::at()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// if let Some(item) = cycle_cursor.at_mut(2) {
+    ///     *item = 30;
+    /// }
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2, 30]);
+    /// assert_eq!(cycle_cursor.at_mut(3), None);
+    /// ```
+    pub fn at_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.inner.get_mut(index)
+    }
+
+    /// Evaluate the predicate once for every element and return the results
+    /// as a mask indexed like the underlying vector.
+    ///
+    /// The mask can be reused across several navigation calls, such as
+    /// [`Self::cycle_next_where_mask()`], to avoid evaluating an expensive
+    /// predicate repeatedly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// let mask = cycle_cursor.matching_mask(|item| item % 2 == 0);
+    /// assert_eq!(mask, vec![false, true, false, true]);
+    /// ```
+    #[must_use]
+    pub fn matching_mask<F: FnMut(&T) -> bool>(&self, pred: F) -> Vec<bool> {
+        self.inner.iter().map(pred).collect()
+    }
+
+    /// Move the cursor forward to the next position set in a mask, such as one
+    /// produced by [`Self::matching_mask()`], and return the element there.
+    ///
+    /// The search starts at the element after the cursor and wraps around at
+    /// most once, so the pointed element itself is checked last. If the cursor
+    /// points to [`None`], the search starts at the first element, as with
+    /// [`Self::cycle_next()`]. Positions missing from a short mask count as
+    /// unset. Returns [`None`] leaving the cursor untouched if no position is
+    /// set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// let mask = cycle_cursor.matching_mask(|item| item % 2 == 0);
+    ///
+    /// assert_eq!(cycle_cursor.cycle_next_where_mask(&mask), Some(&2));
+    /// assert_eq!(cycle_cursor.cycle_next_where_mask(&mask), Some(&4));
+    /// assert_eq!(cycle_cursor.cycle_next_where_mask(&mask), Some(&2));
+    /// ```
+    pub fn cycle_next_where_mask(&mut self, mask: &[bool]) -> Option<&T> {
+        let max_items = self.inner.len();
+        let start = self.pos.map_or(max_items, |pos| pos + 1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let index = (0..max_items)
+            .map(|step| (start + step) % max_items)
+            .find(|&index| mask.get(index).copied().unwrap_or(false))?;
+
+        self.land_on(Some(index));
+        self.inner.get(index)
+    }
+
+    /// Search forward at most `radius` steps for an element satisfying the
+    /// predicate, moving the cursor to it.
+    ///
+    /// The search starts at the element after the cursor, or the first element
+    /// if the cursor points to [`None`], as with [`Self::cycle_next()`]. The
+    /// radius is capped at one full wrap, so the pointed element itself is
+    /// only checked last when `radius` reaches the length. Returns the matched
+    /// element, or [`None`] leaving the cursor untouched if nothing matches
+    /// within the radius.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert_eq!(cycle_cursor.find_next_within(2, |item| *item == 4), None);
+    /// assert_eq!(
+    ///     cycle_cursor.find_next_within(3, |item| *item == 4),
+    ///     Some(&4)
+    /// );
+    /// ```
+    pub fn find_next_within<F: FnMut(&T) -> bool>(
+        &mut self,
+        radius: usize,
+        mut pred: F,
+    ) -> Option<&T> {
+        let max_items = self.inner.len();
+        let start = self.pos.map_or(max_items, |pos| pos + 1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let index = (0..radius.min(max_items))
+            .map(|step| (start + step) % max_items)
+            .find(|&index| self.inner.get(index).is_some_and(&mut pred))?;
+
+        self.land_on(Some(index));
+        self.inner.get(index)
+    }
+
+    /// Search backward at most `radius` steps for an element satisfying the
+    /// predicate, moving the cursor to it.
+    ///
+    /// This mirrors [`Self::find_next_within()`], starting at the element
+    /// before the cursor, or the last element if the cursor points to [`None`],
+    /// as with [`Self::cycle_prev()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert_eq!(cycle_cursor.find_prev_within(1, |item| *item == 4), None);
+    /// assert_eq!(
+    ///     cycle_cursor.find_prev_within(2, |item| *item == 4),
+    ///     Some(&4)
+    /// );
+    /// ```
+    pub fn find_prev_within<F: FnMut(&T) -> bool>(
+        &mut self,
+        radius: usize,
+        mut pred: F,
+    ) -> Option<&T> {
+        let max_items = self.inner.len();
+        let base = self.pos.unwrap_or(max_items);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let index = (1..=radius.min(max_items))
+            .map(|step| (base + max_items - step) % max_items)
+            .find(|&index| self.inner.get(index).is_some_and(&mut pred))?;
+
+        self.land_on(Some(index));
+        self.inner.get(index)
+    }
+
+    /// Scroll the content and the cursor at the same time: the elements move
+    /// `by` positions backward while the cursor moves `by` positions forward.
+    ///
+    /// This models scrolling a window over moving content. [`Self::seek()`]
+    /// only moves the cursor and [`Self::shift_values()`] only moves the
+    /// elements, while here both happen at once, so the physical layout shifts
+    /// and afterwards [`Self::get()`] returns the element that was `2 * by`
+    /// positions ahead of the cursor. Negative values scroll the other way. A
+    /// cursor pointing to [`None`] moves as in [`Self::seek()`].
+    ///
+    /// A positive `by` is a [`slice::rotate_left()`] of the underlying vector,
+    /// the opposite of [`Self::shift_values()`] for the same sign. Moving the
+    /// elements forward together with the cursor would leave [`Self::get()`]
+    /// unchanged, like the `rotate_*` methods do.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.scroll(1);
+    /// assert_eq!(cycle_cursor.inner, vec![2, 3, 4, 5, 6, 1]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &3);
+    /// ```
+    pub fn scroll(&mut self, by: isize) {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return;
+        }
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let shift = by.unsigned_abs() % max_items;
+
+        if by < 0 {
+            self.inner.rotate_right(shift);
+        } else {
+            self.inner.rotate_left(shift);
+        }
+        self.seek(by);
+    }
+
+    /// Fold over every element at most once in cursor order, stopping at the
+    /// first error returned by the closure.
+    ///
+    /// This is the fallible counterpart of [`Self::fold_from_cursor()`]. For an
+    /// empty vector `Ok(init)` is returned.
+    ///
+    /// # Errors
+    /// Returns the first error produced by `f`, without visiting any further
+    /// elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let sum = cycle_cursor.try_fold_from_cursor(0, |acc, item| Ok::<_, ()>(acc + item));
+    /// assert_eq!(sum, Ok(10));
+    ///
+    /// let checked =
+    ///     cycle_cursor.try_fold_from_cursor(
+    ///         0,
+    ///         |acc, &item| {
+    ///             if item == 1 { Err(acc) } else { Ok(acc + item) }
+    ///         },
+    ///     );
+    /// assert_eq!(checked, Err(7));
+    /// ```
+    pub fn try_fold_from_cursor<B, E, F: FnMut(B, &T) -> Result<B, E>>(
+        &self,
+        init: B,
+        f: F,
+    ) -> Result<B, E> {
+        self.iter_from_cursor().try_fold(init, f)
+    }
+
+    /// Replace the underlying vector while keeping the selection on the same
+    /// value, e.g. when refreshing a live listing.
+    ///
+    /// If the previously pointed element is found in the new vector, the
+    /// cursor moves to its first occurrence. Otherwise, or if the cursor
+    /// pointed to [`None`] or was stale, it is reset to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec!["a.txt", "b.txt", "c.txt"];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// cycle_cursor.replace_inner_keep_selection(vec!["0.txt", "a.txt", "b.txt"]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &"b.txt");
+    ///
+    /// cycle_cursor.replace_inner_keep_selection(vec!["a.txt"]);
+    /// assert_eq!(cycle_cursor.get(), None);
+    /// ```
+    pub fn replace_inner_keep_selection(&mut self, inner: Vec<T>)
+    where
+        T: PartialEq,
+    {
+        let selected = self.current_index().and_then(|index| self.inner.get(index));
+
+        self.pos = selected.and_then(|value| inner.iter().position(|item| item == value));
+        self.inner = inner;
+    }
+
+    /// Remove every element equal to one of the `window` elements before it,
+    /// so only nearby repeats are dropped.
+    ///
+    /// Elements are scanned in absolute order from the first to the last, and
+    /// each one is compared against the `window` positions preceding it in the
+    /// original vector, whether those were kept or not. The scan does not wrap
+    /// around, so the first elements are never compared against the last ones.
+    /// As with [`Self::dedup_all_cursor()`], a cursor pointing to a removed
+    /// duplicate lands on the kept earlier copy, and a cursor pointing to
+    /// [`None`] stays that way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 1, 3, 4, 1];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// cycle_cursor.dedup_within_cursor(2);
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2, 3, 4, 1]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    /// assert_eq!(cycle_cursor.pos.unwrap(), 0);
+    /// ```
+    pub fn dedup_within_cursor(&mut self, window: usize)
+    where
+        T: PartialEq,
+    {
+        let mut keep = Vec::with_capacity(self.inner.len());
+        let mut new_indices: Vec<usize> = Vec::with_capacity(self.inner.len());
+        let mut kept_count = 0;
+
+        for (index, item) in self.inner.iter().enumerate() {
+            let kept_copy = (index.saturating_sub(window)..index)
+                .rev()
+                .find(|&earlier| self.inner.get(earlier) == Some(item))
+                .and_then(|earlier| new_indices.get(earlier).copied());
+
+            keep.push(kept_copy.is_none());
+            new_indices.push(kept_copy.unwrap_or(kept_count));
+            if kept_copy.is_none() {
+                kept_count += 1;
+            }
+        }
+
+        self.pos = self
+            .current_index()
+            .and_then(|pos| new_indices.get(pos).copied());
+
+        let mut keep_iter = keep.into_iter();
+        self.inner.retain(|_| keep_iter.next().unwrap_or(true));
+    }
+
+    /// Return the relative position of the cursor between the first (`0.0`)
+    /// and last (`1.0`) elements, e.g. for drawing a progress bar.
+    ///
+    /// This is the inverse of [`Self::seek_to_fraction()`]. A single element
+    /// vector reports `Some(0.0)` rather than dividing by zero. Returns
+    /// [`None`] if the cursor points to [`None`] or is stale.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert_eq!(cycle_cursor.progress(), None);
+    ///
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(cycle_cursor.progress(), Some(0.25));
+    /// ```
+    #[must_use]
+    pub fn progress(&self) -> Option<f64> {
+        let pos = self.current_index()?;
+        let last_index = self.inner.len() - 1;
+
+        if last_index == 0 {
+            return Some(0.0);
+        }
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "a ratio only needs to be approximate for huge buffers"
+        )]
+        let ratio = pos as f64 / last_index as f64;
+        Some(ratio.clamp(0.0, 1.0))
+    }
+
+    /// Compute the maximum of every cyclic window of `window` elements, in the
+    /// same order as [`Self::windows_cyclic()`].
+    ///
+    /// A monotonic deque of candidates is kept while sliding over one pass, so
+    /// this takes `O(n)` time regardless of the window size, instead of the
+    /// `O(n * window)` of taking the maximum of every window separately.
+    /// Windows longer than the vector repeat elements and therefore all yield
+    /// the overall maximum. An empty [`Vec`] is returned if `window` is `0` or
+    /// the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 3, 2, 0, 1];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// let maxima = cycle_cursor.sliding_max_cyclic(2);
+    /// assert_eq!(maxima, vec![&3, &3, &2, &1, &1]);
+    /// ```
+    #[must_use]
+    pub fn sliding_max_cyclic(&self, window: usize) -> Vec<&T>
+    where
+        T: Ord,
+    {
+        let max_items = self.inner.len();
+        if window == 0 || max_items == 0 {
+            return Vec::new();
+        }
+
+        let window = window.min(max_items);
+        let start = self.start_index();
+        #[allow(clippy::integer_division_remainder_used)]
+        let item_at = |step: usize| self.inner.get((start + step) % max_items);
+
+        let mut maxima = Vec::with_capacity(max_items);
+        let mut candidates: VecDeque<usize> = VecDeque::with_capacity(window);
+
+        for step in 0..max_items + window - 1 {
+            while candidates
+                .back()
+                .is_some_and(|&candidate| item_at(candidate) <= item_at(step))
+            {
+                let _: Option<usize> = candidates.pop_back();
+            }
+            candidates.push_back(step);
+
+            if candidates
+                .front()
+                .is_some_and(|&candidate| candidate + window <= step)
+            {
+                let _: Option<usize> = candidates.pop_front();
+            }
+            if step + 1 >= window {
+                maxima.extend(candidates.front().and_then(|&candidate| item_at(candidate)));
+            }
+        }
+
+        maxima
+    }
+
+    /// Make the cursor point to a valid element, initializing it if needed.
+    ///
+    /// A cursor pointing to [`None`] is moved to the first element and a stale
+    /// position is clamped to the last element, while a valid position is left
+    /// as is. On an empty vector the cursor is set to [`None`]. Calling this
+    /// repeatedly has no further effect, which makes it a convenient guard
+    /// before a batch of [`Self::get()`] calls.
+    ///
+    /// Initializing the cursor counts as a move, so it is recorded in the
+    /// history and reported to the callback registered through
+    /// [`Self::set_on_move()`]. Clamping a stale position only relocates the
+    /// cursor and does neither.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// cycle_cursor.normalize_pos();
+    /// assert_eq!(cycle_cursor.get().unwrap(), &1);
+    ///
+    /// cycle_cursor.seek(-1);
+    /// cycle_cursor.truncate(2);
+    /// cycle_cursor.normalize_pos();
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn normalize_pos(&mut self) {
+        let max_items = self.inner.len();
+
+        match self.pos {
+            _ if max_items == 0 => self.pos = None,
+            None => self.land_on(Some(0)),
+            Some(pos) if pos >= max_items => self.pos = Some(max_items - 1),
+            Some(_) => {}
+        }
+    }
+
+    /// Walk this cursor and `other` in lockstep, each in its own cursor order,
+    /// and collect the step and both elements wherever they differ.
+    ///
+    /// Each walk starts at the pointed element, or the first element if there
+    /// is none, so two rotated sequences are compared aligned at their cursors.
+    /// If the lengths differ, only the first `min(len, other.len)` steps are
+    /// compared and the extra elements of the longer ring are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let mut cycle_cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+    /// let mut other = CycleCursor::from(vec![3, 4, 1, 5]);
+    /// cycle_cursor.cycle_next();
+    /// other.seek(3);
+    ///
+    /// let diff = cycle_cursor.diff_from_cursor(&other);
+    /// assert_eq!(diff, vec![(1, &2, &5)]);
+    /// ```
+    #[must_use]
+    pub fn diff_from_cursor<'a>(&'a self, other: &'a Self) -> Vec<(usize, &'a T, &'a T)>
+    where
+        T: PartialEq,
+    {
+        self.iter_from_cursor()
+            .zip(other.iter_from_cursor())
+            .enumerate()
+            .filter(|(_, (item, other_item))| item != other_item)
+            .map(|(step, (item, other_item))| (step, item, other_item))
+            .collect()
+    }
+
+    /// Stably sort the underlying vector by a key, keeping the cursor on its
+    /// element.
+    ///
+    /// The pointed element is tracked by its original index, so the cursor
+    /// follows the right element even among several with equal keys. A cursor
+    /// pointing to [`None`] or a stale position is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec!["ccc", "a", "bb", "d"];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(4);
+    ///
+    /// cycle_cursor.sort_by_key_cursor(|item| item.len());
+    /// assert_eq!(cycle_cursor.inner, vec!["a", "d", "bb", "ccc"]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &"d");
+    /// ```
+    pub fn sort_by_key_cursor<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        let pos = self.current_index();
+
+        let mut indexed: Vec<(usize, T)> = self.inner.drain(..).enumerate().collect();
+        indexed.sort_by_key(|(_, item)| f(item));
+
+        if let Some(pos) = pos {
+            self.pos = indexed.iter().position(|&(index, _)| index == pos);
+        }
+        self.inner = indexed.into_iter().map(|(_, item)| item).collect();
+    }
+
+    /// Count the elements strictly after the cursor, up to the last element,
+    /// e.g. for "N more items" labels.
+    ///
+    /// Unlike [`Self::seek()`] this does not wrap around, so it returns `0` on
+    /// the last element. Returns [`None`] if the cursor points to [`None`] or
+    /// is stale.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert_eq!(cycle_cursor.remaining_forward(), None);
+    ///
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(cycle_cursor.remaining_forward(), Some(2));
+    /// ```
+    #[must_use]
+    pub fn remaining_forward(&self) -> Option<usize> {
+        self.current_index().map(|pos| self.inner.len() - 1 - pos)
+    }
+
+    /// Count the elements strictly before the cursor, down to the first
+    /// element, without wrapping around. See [`Self::remaining_forward()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert_eq!(cycle_cursor.remaining_backward(), None);
+    ///
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(cycle_cursor.remaining_backward(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn remaining_backward(&self) -> Option<usize> {
+        self.current_index()
+    }
+
+    /// Split the ring into two cursors at a signed offset relative to the
+    /// cursor, wrapping around.
+    ///
+    /// The elements are taken in cursor order, starting at the pointed element
+    /// or at the first element if the cursor points to [`None`] or is stale.
+    /// The first cursor holds the elements from there up to, but excluding,
+    /// the element at `offset`, and the second cursor holds the rest. The
+    /// offset wraps like [`Self::seek()`], so a negative offset counts back
+    /// from the end of the pass and an offset of `0` leaves the first cursor
+    /// empty. Each non-empty cursor points to its first element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(4);
+    ///
+    /// let (head, rest) = cycle_cursor.split_at_offset(2);
+    /// assert_eq!(head.inner, vec![4, 5]);
+    /// assert_eq!(rest.inner, vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn split_at_offset(mut self, offset: isize) -> (Self, Self) {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return (
+                Self::from_parts(Vec::new(), None),
+                Self::from_parts(Vec::new(), None),
+            );
+        }
+
+        let start = self.start_index();
+        self.inner.rotate_left(start);
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_wrap,
+            reason = "vector lengths never exceed `isize::MAX`"
+        )]
+        let split = offset.rem_euclid(max_items as isize) as usize;
+        let rest = self.inner.split_off(split);
+        let head_pos = (!self.inner.is_empty()).then_some(0);
+        let rest_pos = (!rest.is_empty()).then_some(0);
+
+        (
+            Self::from_parts(self.inner, head_pos),
+            Self::from_parts(rest, rest_pos),
+        )
+    }
+
+    /// Move the cursor to a position, validating it against the underlying
+    /// vector.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::OutOfBounds`] if `pos` is not a valid index into
+    /// the underlying vector. The cursor is left untouched on error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::{CursorError, CycleCursor};
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.set_pos(Some(2)), Ok(()));
+    /// assert_eq!(cycle_cursor.get().unwrap(), &3);
+    /// assert_eq!(cycle_cursor.set_pos(Some(3)), Err(CursorError::OutOfBounds));
+    /// ```
+    pub fn set_pos(&mut self, pos: Option<usize>) -> Result<(), CursorError> {
+        if pos.is_some_and(|index| index >= self.inner.len()) {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        self.land_on(pos);
+        Ok(())
+    }
+
+    /// Register a callback invoked with the old and new position whenever the
+    /// cursor moves, replacing any previous callback.
+    ///
+    /// The callback fires for navigation such as [`Self::cycle_next()`],
+    /// [`Self::cycle_prev()`], [`Self::seek()`] or [`Self::set_pos()`], but not
+    /// when a move leaves the position unchanged, nor when the cursor is only
+    /// relocated to follow its element after the underlying vector changed.
+    /// This allows syncing external state, like a scroll offset, without
+    /// polling.
+    ///
+    /// Clones of the cursor do not keep the callback, so a cloned cursor moves
+    /// silently until a callback is registered on it. If the callback panics,
+    /// it is dropped and not invoked again.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// # use std::sync::{Arc, Mutex};
+    /// #
+    /// let source = vec![1, 2, 3];
+    /// let moves = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// let recorded = Arc::clone(&moves);
+    /// cycle_cursor.set_on_move(move |old, new| recorded.lock().unwrap().push((old, new)));
+    ///
+    /// cycle_cursor.cycle_next();
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(*moves.lock().unwrap(), vec![
+    ///     (None, Some(0)),
+    ///     (Some(0), Some(2))
+    /// ]);
+    /// ```
+    pub fn set_on_move<F: FnMut(Option<usize>, Option<usize>) + Send + 'static>(&mut self, f: F) {
+        self.on_move = OnMove(Some(AssertUnwindSafe(Box::new(f))));
+    }
+
+    /// Remove the callback registered through [`Self::set_on_move()`].
+    pub fn clear_on_move(&mut self) {
+        self.on_move = OnMove(None);
+    }
+
+    /// Rotate the underlying vector by the smallest amount, forward or
+    /// backward, that brings an element satisfying the predicate to index `0`,
+    /// keeping the cursor on its element.
+    ///
+    /// When a match is equally far in both directions, the rotation that moves
+    /// elements towards lower indices wins. Returns `false` and leaves the
+    /// vector untouched if nothing matches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// assert!(cycle_cursor.rotate_to_nearest(|item| item % 3 == 0));
+    /// assert_eq!(cycle_cursor.inner, vec![6, 1, 2, 3, 4, 5]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn rotate_to_nearest<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        let max_items = self.inner.len();
+        let Some(target) = self
+            .inner
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| pred(item))
+            .map(|(index, _)| index)
+            .min_by_key(|&index| {
+                let offset = self.signed_offset(index);
+                (offset.unsigned_abs(), offset < 0)
+            })
+        else {
+            return false;
+        };
+
+        let offset = self.signed_offset(target);
+        if offset < 0 {
+            self.inner.rotate_right(offset.unsigned_abs());
+        } else {
+            self.inner.rotate_left(target);
+        }
+
+        if let Some(pos) = self.current_index() {
+            #[allow(clippy::integer_division_remainder_used)]
+            let new_pos = (pos + max_items - target) % max_items;
+            self.pos = Some(new_pos);
+        }
+
+        true
+    }
+
+    /// Count the unique elements, regardless of the cursor position.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 1, 3, 2];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.count_distinct(), 3);
+    /// ```
+    #[must_use]
+    pub fn count_distinct(&self) -> usize
+    where
+        T: Eq + Hash,
+    {
+        self.inner.iter().collect::<HashSet<_>>().len()
+    }
+
+    /// Collect the unique elements in the order they are first seen in the
+    /// underlying vector, regardless of the cursor position.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![3, 1, 3, 2, 1];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.distinct_values(), vec![&3, &1, &2]);
+    /// ```
+    #[must_use]
+    pub fn distinct_values(&self) -> Vec<&T>
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        self.inner
+            .iter()
+            .filter(|&item| seen.insert(item))
+            .collect()
+    }
+
+    /// Move the cursor by a signed offset as in [`Self::seek()`], then return
+    /// a mutable reference to the newly pointed element.
+    ///
+    /// Returns [`None`] if the underlying vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// if let Some(item) = cycle_cursor.seek_get_mut(2) {
+    ///     *item *= 10;
+    /// }
+    /// assert_eq!(cycle_cursor.get().unwrap(), &20);
+    /// ```
+    pub fn seek_get_mut(&mut self, offset: isize) -> Option<&mut T> {
+        self.seek(offset);
+
+        let pos = self.current_index()?;
+        self.inner.get_mut(pos)
+    }
+
+    /// Rotate the underlying vector to the rotation with the most element-wise
+    /// matches against `reference`, keeping the cursor on its element.
+    ///
+    /// This aligns two noisy periodic sequences. Every rotation is scored,
+    /// which takes `O(n²)` comparisons, and among equally good rotations the
+    /// smallest forward rotation wins, so an already aligned vector is left
+    /// untouched. Returns `false` without changes if the lengths differ.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let reference = CycleCursor::from(vec![1, 2, 3, 4, 5]);
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(vec![4, 0, 1, 2, 3]);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert!(cycle_cursor.align_with(&reference));
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2, 3, 4, 0]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &4);
+    /// ```
+    pub fn align_with(&mut self, reference: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        let max_items = self.inner.len();
+        if max_items != reference.inner.len() {
+            return false;
+        }
+
+        let matches = |shift: usize| {
+            let (head, tail) = self.inner.split_at(shift);
+            tail.iter()
+                .chain(head)
+                .zip(&reference.inner)
+                .filter(|(item, reference_item)| item == reference_item)
+                .count()
+        };
+        let (best_shift, _) = (0..max_items).fold((0, 0), |best, shift| {
+            let score = matches(shift);
+            if score > best.1 { (shift, score) } else { best }
+        });
+
+        self.inner.rotate_left(best_shift);
+        if let Some(pos) = self.current_index() {
+            #[allow(clippy::integer_division_remainder_used)]
+            let new_pos = (pos + max_items - best_shift) % max_items;
+            self.pos = Some(new_pos);
+        }
+
+        true
+    }
+
+    /// Remove and return every `n`-th element, counting from `1` at the start
+    /// of the underlying vector, e.g. to downsample a ring buffer.
+    ///
+    /// The cursor keeps pointing to its element, or moves to the next
+    /// surviving element if its element was removed, as in
+    /// [`Self::extract_if_cursor()`].
+    ///
+    /// # Panics
+    /// Calling this method will panic if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// assert_eq!(cycle_cursor.decimate(3), vec![3, 6]);
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2, 4, 5, 7]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &4);
+    /// ```
+    pub fn decimate(&mut self, n: usize) -> Vec<T> {
+        assert!(n != 0, "Decimation step must be non-zero");
+
+        let mut index: usize = 0;
+        self.extract_relocating(|_| {
+            index += 1;
+            #[allow(clippy::integer_division_remainder_used)]
+            let is_nth = index % n == 0;
+            is_nth
+        })
+    }
+
+    /// Pair every element with the element `d` positions after it, over one
+    /// pass in cursor order starting at the pointed element (or the first
+    /// element if there is none).
+    ///
+    /// The second element of each pair wraps around the end of the underlying
+    /// vector, and `d` is taken modulo the length, so a distance of `0` (or any
+    /// multiple of the length) pairs each element with itself. This supports
+    /// autocorrelation-style computations on periodic data. Nothing is yielded
+    /// for an empty vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let pairs: Vec<_> = cycle_cursor.pairs_at_distance(1).collect();
+    /// assert_eq!(pairs, vec![(&3, &4), (&4, &1), (&1, &2), (&2, &3)]);
+    /// ```
+    pub fn pairs_at_distance(&self, d: usize) -> impl Iterator<Item = (&T, &T)> {
+        let max_items = self.inner.len();
+        let start = self.start_index();
+
+        #[allow(clippy::integer_division_remainder_used)]
+        (0..max_items).filter_map(move |step| {
+            let index = (start + step) % max_items;
+            let other_index = (index + d % max_items) % max_items;
+            self.inner.get(index).zip(self.inner.get(other_index))
+        })
+    }
+
+    /// Try to reserve capacity for at least `additional` more elements in the
+    /// underlying vector, as in [`Vec::try_reserve`].
+    ///
+    /// The length is unchanged, so the cursor position stays valid.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the capacity overflows or the
+    /// allocator reports a failure.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// cycle_cursor.try_reserve(10).unwrap();
+    /// assert!(cycle_cursor.capacity() >= 13);
+    /// assert!(cycle_cursor.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    /// Move the cursor by a uniformly random offset in
+    /// `[-max_steps, max_steps]`, wrapping around, e.g. to add randomness to a
+    /// round-robin scheduler.
+    ///
+    /// If the cursor points to [`None`] or is stale, the move starts from the
+    /// first element. On an empty vector the cursor is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    /// let mut rng = StdRng::seed_from_u64(7);
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.jitter_pos(1, &mut rng);
+    ///
+    /// assert!([&5, &1, &2].contains(&cycle_cursor.get().unwrap()));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn jitter_pos<R: rand::Rng>(&mut self, max_steps: usize, rng: &mut R) {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return;
+        }
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "`usize` widens losslessly to `i128` and the remainder is below the length"
+        )]
+        let max_steps = max_steps as i128;
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "`usize` widens losslessly to `i128` and the remainder is below the length"
+        )]
+        let offset = rng
+            .random_range(-max_steps..=max_steps)
+            .rem_euclid(max_items as i128) as usize;
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let pos = (self.start_index() + offset) % max_items;
+        self.land_on(Some(pos));
+    }
+
+    /// Run-length encode one pass in cursor order, starting at the pointed
+    /// element (or the first element if there is none), as pairs of each
+    /// run's first element and its length.
+    ///
+    /// As with [`Self::group_runs()`], runs are never merged across the end
+    /// of the pass, so if the last run has the same value as the first they
+    /// are still reported separately. For an empty vector an empty [`Vec`] is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 1, 2, 2, 2, 1];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let runs = cycle_cursor.run_length_from_cursor();
+    /// assert_eq!(runs, vec![(&1, 1), (&2, 3), (&1, 2)]);
+    /// ```
+    #[must_use]
+    pub fn run_length_from_cursor(&self) -> Vec<(&T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut runs: Vec<(&T, usize)> = Vec::new();
+
+        for item in self.iter_from_cursor() {
+            match runs.last_mut() {
+                Some((value, count)) if *value == item => *count += 1,
+                _ => runs.push((item, 1)),
+            }
+        }
+
+        runs
+    }
+
+    /// Find the signed rotation that makes the underlying vector equal to that
+    /// of `target`, or [`None`] if no rotation does.
+    ///
+    /// The result is the amount to pass to [`Self::scroll()`] to apply the
+    /// rotation, so a positive value moves elements towards lower indices. If
+    /// several rotations work, the one with the smallest magnitude is returned,
+    /// preferring a positive value on ties. Two empty vectors match with `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let target = CycleCursor::from(vec![3, 4, 5, 1, 2]);
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(vec![1, 2, 3, 4, 5]);
+    ///
+    /// let shift = cycle_cursor.shift_to_match(&target);
+    /// assert_eq!(shift, Some(2));
+    ///
+    /// cycle_cursor.scroll(shift.unwrap());
+    /// assert_eq!(cycle_cursor.inner, target.inner);
+    /// ```
+    #[must_use]
+    pub fn shift_to_match(&self, target: &Self) -> Option<isize>
+    where
+        T: PartialEq,
+    {
+        let max_items = self.inner.len();
+        if max_items != target.inner.len() {
+            return None;
+        }
+        if max_items == 0 {
+            return Some(0);
+        }
+
+        (0..max_items)
+            .filter(|&shift| {
+                let (head, tail) = self.inner.split_at(shift);
+                tail.iter().chain(head).eq(&target.inner)
+            })
+            .map(|shift| self.signed_offset(shift))
+            .min_by_key(|&offset| (offset.unsigned_abs(), offset < 0))
+    }
+
+    /// Scan over one pass in reverse cursor order, threading a state through
+    /// the closure and collecting its outputs.
+    ///
+    /// The pass starts at the pointed element (or the first element if there
+    /// is none) and walks backward, wrapping from the first element to the
+    /// last, in the same order as [`Self::iter_cycle_rev()`]. As with
+    /// [`Iterator::scan`], the scan stops early once the closure returns
+    /// [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let sums = cycle_cursor.scan_back_from_cursor(0, |sum, item| {
+    ///     *sum += item;
+    ///     Some(*sum)
+    /// });
+    /// assert_eq!(sums, vec![2, 3, 7, 10]);
+    /// ```
+    #[must_use]
+    pub fn scan_back_from_cursor<St, B, F>(&self, init: St, f: F) -> Vec<B>
+    where
+        F: FnMut(&mut St, &T) -> Option<B>,
+    {
+        self.iter_cycle_rev()
+            .take(self.inner.len())
+            .scan(init, f)
+            .collect()
+    }
+
+    /// Return the current cursor position together with the pointed element.
+    ///
+    /// Unlike [`Self::get()`], which panics on a stale position, this returns
+    /// [`None`] if the cursor points to [`None`] or is stale.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// assert_eq!(cycle_cursor.get_indexed(), Some((2, &3)));
+    /// ```
+    #[must_use]
+    pub fn get_indexed(&self) -> Option<(usize, &T)> {
+        let pos = self.current_index()?;
+        self.inner.get(pos).map(|item| (pos, item))
+    }
+
+    /// Rotate the underlying vector so its longest run of consecutive equal
+    /// elements starts at index `0`, and point the cursor to it.
+    ///
+    /// Runs are found cyclically, so a run wrapping around the end of the
+    /// vector counts as a single run. If several runs are equally long, the
+    /// one starting at the lowest index wins. If all elements are equal the
+    /// vector is left as is. On an empty vector the cursor is set to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![2, 1, 1, 3, 2, 2];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.rotate_to_longest_run();
+    ///
+    /// assert_eq!(cycle_cursor.inner, vec![2, 2, 2, 1, 1, 3]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn rotate_to_longest_run(&mut self)
+    where
+        T: PartialEq,
+    {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            self.pos = None;
+            return;
+        }
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let run_starts: Vec<usize> = (0..max_items)
+            .filter(|&index| {
+                self.inner.get(index) != self.inner.get((index + max_items - 1) % max_items)
+            })
+            .collect();
+
+        let mut longest = (0, 0);
+        for (&start, &next_start) in run_starts.iter().zip(run_starts.iter().cycle().skip(1)) {
+            #[allow(clippy::integer_division_remainder_used)]
+            let run_len = (next_start + max_items - start) % max_items;
+            if run_len > longest.1 {
+                longest = (start, run_len);
+            }
+        }
+
+        self.inner.rotate_left(longest.0);
+        self.pos = Some(0);
+    }
+
+    /// Clone `count` consecutive elements, starting at the element
+    /// [`Self::peek()`] would return for `start_offset` and wrapping around as
+    /// often as needed.
+    ///
+    /// Offsets of any magnitude wrap around, and `count` may exceed the length,
+    /// in which case elements repeat. For an empty vector an empty [`Vec`] is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// assert_eq!(cycle_cursor.export_window(-1, 3), vec![2, 3, 4]);
+    /// assert_eq!(cycle_cursor.export_window(1, 6), vec![4, 1, 2, 3, 4, 1]);
+    /// ```
+    #[must_use]
+    pub fn export_window(&self, start_offset: isize, count: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return Vec::new();
+        }
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_wrap,
+            reason = "vector lengths never exceed `isize::MAX`"
+        )]
+        let offset = start_offset.rem_euclid(max_items as isize) as usize;
+        #[allow(clippy::integer_division_remainder_used)]
+        let start = (self.pos.unwrap_or(max_items - 1) + offset) % max_items;
+
+        self.inner
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(count)
+            .cloned()
+            .collect()
+    }
+
+    /// Check whether the underlying vector reads the same forward and
+    /// backward, ignoring the cursor position.
+    ///
+    /// Empty and single element vectors are palindromes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// assert!(CycleCursor::from(vec![1, 2, 1]).is_palindrome());
+    /// assert!(!CycleCursor::from(vec![1, 2, 2]).is_palindrome());
+    /// ```
+    #[must_use]
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.iter().eq(self.inner.iter().rev())
+    }
+
+    /// Check whether one pass in cursor order, starting at the pointed element
+    /// (or the first element if there is none), reads the same forward and
+    /// backward.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![2, 1, 2, 3, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert!(!cycle_cursor.is_palindrome_from_cursor());
+    ///
+    /// cycle_cursor.cycle_prev();
+    /// assert!(cycle_cursor.is_palindrome_from_cursor());
+    /// ```
+    #[must_use]
+    pub fn is_palindrome_from_cursor(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let (tail, head) = self.spans_from_cursor();
+        tail.iter()
+            .chain(head)
+            .eq(head.iter().rev().chain(tail.iter().rev()))
+    }
+
+    /// Merge two cursors whose underlying vectors are sorted in ascending
+    /// order into a single sorted cursor pointing to [`None`].
+    ///
+    /// Both vectors must already be sorted, which lets the merge run in
+    /// `O(n + m)`. If either is not sorted, the result contains all elements
+    /// but is not guaranteed to be sorted. Equal elements from `self` come
+    /// before those from `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let cycle_cursor = CycleCursor::from(vec![1, 4, 6]);
+    /// let other = CycleCursor::from(vec![2, 4, 5, 9]);
+    ///
+    /// let merged = cycle_cursor.merge_sorted(other);
+    /// assert_eq!(merged.inner, vec![1, 2, 4, 4, 5, 6, 9]);
+    /// assert_eq!(merged.pos, None);
+    /// ```
+    #[must_use]
+    pub fn merge_sorted(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        let mut merged = Vec::with_capacity(self.inner.len() + other.inner.len());
+        let mut own = self.inner.into_iter().peekable();
+        let mut others = other.inner.into_iter().peekable();
+
+        loop {
+            let next = match (own.peek(), others.peek()) {
+                (Some(item), Some(other_item)) if other_item < item => others.next(),
+                (Some(_), _) => own.next(),
+                (None, _) => others.next(),
+            };
+
+            let Some(item) = next else {
+                break;
+            };
+            merged.push(item);
+        }
+
+        Self::from_parts(merged, None)
+    }
+
+    /// Peek the element at an offset from the cursor as in [`Self::peek()`],
+    /// returning its absolute index along with it.
+    ///
+    /// The index can be passed to [`Self::set_pos()`] to move onto the peeked
+    /// element. Offsets of any magnitude wrap around.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::Empty`] if the underlying vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::{CursorError, CycleCursor};
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// assert_eq!(cycle_cursor.peek_indexed(-2), Ok((3, &4)));
+    ///
+    /// let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+    /// assert_eq!(empty_cursor.peek_indexed(1), Err(CursorError::Empty));
+    /// ```
+    pub fn peek_indexed(&self, offset: isize) -> Result<(usize, &T), CursorError> {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return Err(CursorError::Empty);
+        }
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_wrap,
+            reason = "vector lengths never exceed `isize::MAX`"
+        )]
+        let offset = offset.rem_euclid(max_items as isize) as usize;
+        #[allow(clippy::integer_division_remainder_used)]
+        let index = (self.pos.unwrap_or(max_items - 1) + offset) % max_items;
+
+        self.inner
+            .get(index)
+            .map(|item| (index, item))
+            .ok_or(CursorError::OutOfBounds)
+    }
+
+    /// Collect elements in cursor order, starting at the pointed element (or
+    /// the first element if there is none), until an element equal to one
+    /// already collected is reached.
+    ///
+    /// This extracts the non-repeating prefix of a possibly periodic ring. If
+    /// all elements are distinct, each is collected once. Since only
+    /// [`PartialEq`] is required, every element is compared against the
+    /// collected ones, which takes `O(n²)` comparisons in the worst case.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 1, 2];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(cycle_cursor.collect_until_repeat(), vec![&1, &2, &3]);
+    /// ```
+    #[must_use]
+    pub fn collect_until_repeat(&self) -> Vec<&T>
+    where
+        T: PartialEq,
+    {
+        let mut collected: Vec<&T> = Vec::new();
+
+        for item in self.iter_from_cursor() {
+            if collected.contains(&item) {
+                break;
+            }
+            collected.push(item);
+        }
+
+        collected
+    }
+
+    /// Overwrite every element with clones of `value`, as in [`slice::fill`].
+    ///
+    /// The length is unchanged, so the cursor position stays valid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// cycle_cursor.fill(7);
+    /// assert_eq!(cycle_cursor.get(), Some(&7));
+    /// assert_eq!(cycle_cursor.inner, vec![7, 7, 7]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.inner.fill(value);
+    }
+
+    /// Overwrite every element with values returned by calling `f`
+    /// repeatedly, as in [`slice::fill_with`].
+    ///
+    /// The length is unchanged, so the cursor position stays valid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// let mut counter = 10;
+    ///
+    /// cycle_cursor.fill_with(|| {
+    ///     counter += 1;
+    ///     counter
+    /// });
+    /// assert_eq!(cycle_cursor.inner, vec![11, 12, 13]);
+    /// ```
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.inner.fill_with(f);
+    }
+
+    /// Shuffle every element except the currently pointed one, which stays at
+    /// its index so [`Self::get()`] is unchanged.
+    ///
+    /// This re-randomizes the other options while keeping the current choice
+    /// fixed in place. If the cursor points to [`None`] or is stale, every
+    /// element is shuffled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    /// let mut rng = StdRng::seed_from_u64(7);
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    /// cycle_cursor.reshuffle_keeping_current(&mut rng);
+    ///
+    /// assert_eq!(cycle_cursor.pos, Some(2));
+    /// assert_eq!(cycle_cursor.get(), Some(&3));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn reshuffle_keeping_current<R: rand::Rng>(&mut self, rng: &mut R) {
+        use rand::seq::SliceRandom;
+
+        let Some(pos) = self.current_index() else {
+            self.inner.shuffle(rng);
+            return;
+        };
+
+        // Park the pointed element at the end while shuffling the others
+        let last = self.inner.len() - 1;
+        self.inner.swap(pos, last);
+        if let Some((_, rest)) = self.inner.split_last_mut() {
+            rest.shuffle(rng);
+        }
+        self.inner.swap(pos, last);
+    }
+
+    /// Store a per-element availability mask, consulted by
+    /// [`Self::cycle_next_available()`] and [`Self::cycle_prev_available()`].
+    ///
+    /// Positions missing from the mask after the underlying vector grows count
+    /// as unavailable.
+    ///
+    /// # Errors
+    /// Returns [`CursorError::OutOfBounds`] if the mask length does not match
+    /// the length of the underlying vector. The stored mask is left untouched
+    /// on error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::{CursorError, CycleCursor};
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    ///
+    /// assert_eq!(
+    ///     cycle_cursor.set_availability(vec![true, false, true]),
+    ///     Ok(())
+    /// );
+    /// assert_eq!(
+    ///     cycle_cursor.set_availability(vec![true]),
+    ///     Err(CursorError::OutOfBounds)
+    /// );
+    /// ```
+    pub fn set_availability(&mut self, mask: Vec<bool>) -> Result<(), CursorError> {
+        if mask.len() != self.inner.len() {
+            return Err(CursorError::OutOfBounds);
+        }
+
+        self.availability = Some(mask);
+        Ok(())
+    }
+
+    /// Remove the mask stored through [`Self::set_availability()`], making
+    /// every element available again.
+    pub fn clear_availability(&mut self) {
+        self.availability = None;
+    }
+
+    /// Move the cursor one available position at a time, forward or backward,
+    /// and return the element there.
+    fn cycle_available(&mut self, forward: bool) -> Option<&T> {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return None;
+        }
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let index = (1..=max_items)
+            .map(|step| {
+                if forward {
+                    (self.pos.unwrap_or(max_items - 1) + step) % max_items
+                } else {
+                    (self.pos.unwrap_or(0) % max_items + max_items - step) % max_items
+                }
+            })
+            .find(|&index| {
+                self.availability
+                    .as_ref()
+                    .is_none_or(|mask| mask.get(index).copied().unwrap_or(false))
+            })?;
+
+        self.land_on(Some(index));
+        self.inner.get(index)
+    }
+
+    /// Move the cursor forward to the next available position, as set through
+    /// [`Self::set_availability()`], and return the element there.
+    ///
+    /// The search wraps around at most once, so the pointed element itself is
+    /// checked last. If the cursor points to [`None`], the search starts at the
+    /// first element, as with [`Self::cycle_next()`]. Without a mask every
+    /// element is available. Returns [`None`] leaving the cursor untouched if
+    /// no position is available.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor
+    ///     .set_availability(vec![false, true, false, false, true])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cycle_cursor.cycle_next_available(), Some(&2));
+    /// assert_eq!(cycle_cursor.cycle_next_available(), Some(&5));
+    /// assert_eq!(cycle_cursor.cycle_next_available(), Some(&2));
+    /// ```
+    pub fn cycle_next_available(&mut self) -> Option<&T> {
+        self.cycle_available(true)
+    }
+
+    /// Move the cursor backward to the previous available position, as set
+    /// through [`Self::set_availability()`], and return the element there.
+    ///
+    /// The search wraps around at most once, so the pointed element itself is
+    /// checked last. If the cursor points to [`None`], the search starts at the
+    /// last element, as with [`Self::cycle_prev()`]. Without a mask every
+    /// element is available. Returns [`None`] leaving the cursor untouched if
+    /// no position is available.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor
+    ///     .set_availability(vec![false, true, false, false, true])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cycle_cursor.cycle_prev_available(), Some(&5));
+    /// assert_eq!(cycle_cursor.cycle_prev_available(), Some(&2));
+    /// assert_eq!(cycle_cursor.cycle_prev_available(), Some(&5));
+    /// ```
+    pub fn cycle_prev_available(&mut self) -> Option<&T> {
+        self.cycle_available(false)
+    }
+
+    /// Render the ring on a single line for debugging, such as `1-[2]-3->`.
+    ///
+    /// Elements are written in index order using their [`Display`]
+    /// implementation and joined by `-`. The pointed element is wrapped in
+    /// brackets and the trailing `->` marks the wrap back to the first
+    /// element. If the cursor points to [`None`] or is stale, no element is
+    /// bracketed and ` (no cursor)` is appended. An empty vector renders as
+    /// `(empty)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// assert_eq!(cycle_cursor.ring_diagram(), "1-2-3-> (no cursor)");
+    ///
+    /// cycle_cursor.seek(2);
+    /// assert_eq!(cycle_cursor.ring_diagram(), "1-[2]-3->");
+    /// ```
+    #[must_use]
+    pub fn ring_diagram(&self) -> String
+    where
+        T: Display,
+    {
+        if self.inner.is_empty() {
+            return "(empty)".to_owned();
+        }
+
+        let current = self.current_index();
+        let items: Vec<String> = self
+            .inner
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                if current == Some(index) {
+                    format!("[{item}]")
+                } else {
+                    item.to_string()
+                }
+            })
+            .collect();
+
+        let diagram = format!("{}->", items.join("-"));
+        if current.is_some() {
+            diagram
+        } else {
+            format!("{diagram} (no cursor)")
+        }
+    }
+
+    /// Rotate the underlying vector by the smallest amount, forward or
+    /// backward, that brings an element equal to any of `values` to index `0`,
+    /// keeping the cursor on its element.
+    ///
+    /// This canonicalizes the ring to its nearest anchor out of a set of
+    /// values. Ties are broken as in [`Self::rotate_to_nearest()`]. Returns
+    /// `false` and leaves the vector untouched if nothing matches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(4);
+    ///
+    /// assert!(cycle_cursor.rotate_front_to_any(&[3, 5]));
+    /// assert_eq!(cycle_cursor.inner, vec![3, 4, 5, 6, 1, 2]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &4);
+    /// assert!(!cycle_cursor.rotate_front_to_any(&[7]));
+    /// ```
+    pub fn rotate_front_to_any(&mut self, values: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.rotate_to_nearest(|item| values.contains(item))
+    }
+
+    /// Apply a fallible closure to every element at most once in cursor
+    /// order, stopping at the first error, e.g. for validation passes
+    /// anchored at the cursor.
+    ///
+    /// This is [`Self::try_fold_from_cursor()`] without an accumulator. For an
+    /// empty vector `Ok(())` is returned.
+    ///
+    /// # Errors
+    /// Returns the first error produced by `f`, without visiting any further
+    /// elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let checked =
+    ///     cycle_cursor.try_for_each_from_cursor(|&item| if item > 3 { Err(item) } else { Ok(()) });
+    /// assert_eq!(checked, Err(4));
+    /// ```
+    pub fn try_for_each_from_cursor<E, F: FnMut(&T) -> Result<(), E>>(
+        &self,
+        f: F,
+    ) -> Result<(), E> {
+        self.iter_from_cursor().try_for_each(f)
+    }
+
+    /// Move the cursor by an offset as in [`Self::seek()`] and return how many
+    /// times the movement crossed the boundary between the last and the first
+    /// element, in either direction.
+    ///
+    /// This lets callers detect jumps spanning several loops of the ring. If
+    /// the cursor points to [`None`], the movement starts just before the first
+    /// element, so it lands where [`Self::seek()`] would and a forward seek of
+    /// up to the length crosses no boundary. A stale position is first wrapped
+    /// into bounds. On an empty vector the cursor is left untouched and `0` is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.cycle_next();
+    ///
+    /// assert_eq!(cycle_cursor.seek_counting_wraps(9), 2);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// assert_eq!(cycle_cursor.seek_counting_wraps(-2), 1);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &4);
+    /// ```
+    pub fn seek_counting_wraps(&mut self, offset: isize) -> usize {
+        let max_items = self.inner.len();
+        if max_items == 0 {
+            return 0;
+        }
+
+        #[allow(clippy::integer_division_remainder_used)]
+        #[allow(clippy::as_conversions, reason = "`usize` widens losslessly to `i128`")]
+        let base = self.pos.map_or(-1, |pos| (pos % max_items) as i128);
+        #[allow(clippy::as_conversions, reason = "`isize` widens losslessly to `i128`")]
+        let raw_pos = base + offset as i128;
+        #[allow(clippy::as_conversions, reason = "`usize` widens losslessly to `i128`")]
+        let max_items = max_items as i128;
+
+        #[allow(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "the position is below the length and the wraps are bounded by the offset"
+        )]
+        let (pos, wraps) = (
+            raw_pos.rem_euclid(max_items) as usize,
+            raw_pos.div_euclid(max_items).unsigned_abs() as usize,
+        );
+        self.land_on(Some(pos));
+        wraps
+    }
+
+    /// Iterate over all cyclic windows of `size` elements as in
+    /// [`Self::windows_cyclic()`], each paired with whether it wraps past the
+    /// end of the underlying vector.
+    ///
+    /// This lets renderers treat windows crossing the boundary specially, e.g.
+    /// by drawing a separator. When `size` is between `1` and the length,
+    /// exactly `size - 1` windows wrap. Larger windows always wrap.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3];
+    ///
+    /// let cycle_cursor = CycleCursor::from(source);
+    ///
+    /// let windows: Vec<_> = cycle_cursor.windows_cyclic_flagged(2).collect();
+    /// assert_eq!(windows, vec![
+    ///     (false, vec![&1, &2]),
+    ///     (false, vec![&2, &3]),
+    ///     (true, vec![&3, &1])
+    /// ]);
+    /// ```
+    pub fn windows_cyclic_flagged(&self, size: usize) -> impl Iterator<Item = (bool, Vec<&T>)> {
+        let max_items = self.inner.len();
+        let start = self.start_index();
+
+        self.windows_cyclic(size)
+            .enumerate()
+            .map(move |(offset, window)| {
+                #[allow(clippy::integer_division_remainder_used)]
+                let index = (start + offset) % max_items;
+                (index + size > max_items, window)
+            })
+    }
+
+    /// Clone the ring into a new cursor rotated so the pointed element (or the
+    /// first element if there is none) sits at index `0`, with the new cursor
+    /// pointing to it.
+    ///
+    /// This forks a traversal from the current point while leaving `self`
+    /// untouched, unlike [`Self::rotate_cursor_to_index()`] which rotates in
+    /// place. Marks, history and callbacks are not carried over. An empty
+    /// vector yields an empty cursor pointing to [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(3);
+    ///
+    /// let forked = cycle_cursor.clone_rotated();
+    /// assert_eq!(forked.inner, vec![3, 4, 1, 2]);
+    /// assert_eq!(forked.get().unwrap(), &3);
+    /// assert_eq!(cycle_cursor.inner, vec![1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn clone_rotated(&self) -> Self
+    where
+        T: Clone,
+    {
+        let inner: Vec<T> = self.iter_from_cursor().cloned().collect();
+        let pos = (!inner.is_empty()).then_some(0);
+        Self::from_parts(inner, pos)
+    }
+
+    /// Iterate from the pointed element (or the first element if there is
+    /// none), advancing `step` positions at a time and wrapping around.
+    ///
+    /// Exactly `len` elements are yielded. If `step` and the length are
+    /// coprime every element is visited once. Otherwise only every
+    /// `gcd(step, len)`-th element is reachable, so elements are revisited
+    /// `gcd(step, len)` times each.
+    ///
+    /// # Panics
+    /// Calling this method will panic if `step` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// let stepped: Vec<_> = cycle_cursor.step_iter(2).collect();
+    /// assert_eq!(stepped, vec![&2, &4, &1, &3, &5]);
+    /// ```
+    pub fn step_iter(&self, step: usize) -> impl Iterator<Item = &T> {
+        assert!(step != 0, "Iteration step must be non-zero");
+
+        let max_items = self.inner.len();
+        let start = self.start_index();
+
+        #[allow(clippy::integer_division_remainder_used)]
+        let step = step % max_items.max(1);
+
+        #[allow(clippy::integer_division_remainder_used)]
+        (0..max_items).filter_map(move |count| self.inner.get((start + count * step) % max_items))
+    }
+
+    /// Gather the commonly derived facts about the cursor in a single
+    /// [`CursorSummary`], e.g. for UI code that would otherwise query each of
+    /// them separately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::{CursorSummary, CycleCursor};
+    /// #
+    /// let source = vec![1, 2, 3, 4];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// assert_eq!(cycle_cursor.summary(), CursorSummary {
+    ///     len: 4,
+    ///     pos: Some(1),
+    ///     is_empty: false,
+    ///     is_initialized: true,
+    ///     remaining_forward: Some(2),
+    ///     remaining_backward: Some(1),
+    /// });
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> CursorSummary {
+        CursorSummary {
+            len: self.inner.len(),
+            pos: self.pos,
+            is_empty: self.inner.is_empty(),
+            is_initialized: self.pos.is_some(),
+            remaining_forward: self.remaining_forward(),
+            remaining_backward: self.remaining_backward(),
+        }
+    }
+
+    /// Rotate the underlying vector left so the element at `index` ends up at
+    /// index `0`, keeping the cursor on its element.
+    ///
+    /// `index` must be within bounds of the underlying vector.
+    fn rotate_index_to_front(&mut self, index: usize) {
+        let max_items = self.inner.len();
+        self.inner.rotate_left(index);
+
+        if let Some(pos) = self.current_index() {
+            #[allow(clippy::integer_division_remainder_used)]
+            let new_pos = (pos + max_items - index) % max_items;
+            self.pos = Some(new_pos);
+        }
+    }
+
+    /// Rotate the underlying vector so its maximum element sits at index `0`,
+    /// keeping [`Self::get()`] invariant.
+    ///
+    /// If the maximum occurs several times, its first occurrence is moved to
+    /// the front. Together with [`Self::rotate_to_min()`] this gives common
+    /// canonical anchors beyond the lexicographic [`Self::canonicalize()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![2, 5, 1, 5, 3];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(1);
+    ///
+    /// cycle_cursor.rotate_to_max();
+    /// assert_eq!(cycle_cursor.inner, vec![5, 1, 5, 3, 2]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &2);
+    /// ```
+    pub fn rotate_to_max(&mut self)
+    where
+        T: Ord,
+    {
+        // `max_by_key` keeps the last maximum, so search backwards
+        let Some((index, _)) = self
+            .inner
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, item)| item)
+        else {
+            return;
+        };
+
+        self.rotate_index_to_front(index);
+    }
+
+    /// Rotate the underlying vector so its minimum element sits at index `0`,
+    /// keeping [`Self::get()`] invariant.
+    ///
+    /// If the minimum occurs several times, its first occurrence is moved to
+    /// the front. See [`Self::rotate_to_max()`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![4, 5, 1, 3, 1];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// cycle_cursor.rotate_to_min();
+    /// assert_eq!(cycle_cursor.inner, vec![1, 3, 1, 4, 5]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &5);
+    /// ```
+    pub fn rotate_to_min(&mut self)
+    where
+        T: Ord,
+    {
+        let Some((index, _)) = self.inner.iter().enumerate().min_by_key(|&(_, item)| item) else {
+            return;
+        };
+
+        self.rotate_index_to_front(index);
+    }
+}
+
+/// Operations on rings of optional elements
+impl<T> CycleCursor<Option<T>> {
+    /// Remove every [`None`] element, such as tombstoned entries of a sparse
+    /// ring.
+    ///
+    /// The cursor keeps pointing to its element if it holds a value. If it
+    /// points to a removed [`None`] element, it moves to the next remaining
+    /// element, wrapping around, or to [`None`] if nothing remains.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cycle_cursor::CycleCursor;
+    /// #
+    /// let source = vec![Some(1), None, None, Some(4)];
+    ///
+    /// let mut cycle_cursor = CycleCursor::from(source);
+    /// cycle_cursor.seek(2);
+    ///
+    /// cycle_cursor.compact();
+    /// assert_eq!(cycle_cursor.inner, vec![Some(1), Some(4)]);
+    /// assert_eq!(cycle_cursor.get().unwrap(), &Some(4));
+    /// ```
+    pub fn compact(&mut self) {
+        drop(self.extract_relocating(|item| item.is_none()));
+    }
+}
+
+impl<T> Deref for CycleCursor<T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for CycleCursor<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::Reverse,
+        collections::BTreeSet,
+        panic::{self, UnwindSafe},
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+
+    fn assert_panic<F, R>(panic_fn: F)
+    where
+        F: FnOnce() -> R + UnwindSafe,
+    {
+        let panic_hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = panic::catch_unwind(panic_fn);
+        assert!(result.is_err());
+        panic::set_hook(panic_hook);
+    }
+
+    #[test]
+    fn cursor_cycle_next() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.pos, None);
+        cursor.cycle_next();
+        assert_eq!(cursor.get().unwrap(), &1);
+        cursor.cycle_next();
+        cursor.cycle_next();
+        cursor.cycle_next();
+        assert_eq!(cursor.get().unwrap(), &4);
+        cursor.cycle_next();
+        assert_eq!(cursor.get().unwrap(), &1);
+    }
+
+    #[test]
+    fn cursor_cycle_next_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.pos, None);
+        cursor.cycle_next();
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_cycle_prev() {
+        let source = BTreeSet::from([1, 2, 3, 4]);
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.pos, None);
+        cursor.cycle_prev();
+        assert_eq!(cursor.get().unwrap(), &4);
+        cursor.cycle_prev();
+        cursor.cycle_prev();
+        cursor.cycle_prev();
+        assert_eq!(cursor.get().unwrap(), &1);
+        cursor.cycle_prev();
+        assert_eq!(cursor.get().unwrap(), &4);
+    }
+
+    #[test]
+    fn cursor_cycle_prev_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.pos, None);
+        cursor.cycle_prev();
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_peek() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None
+        assert_eq!(cursor.peek(3).unwrap(), &3);
+
+        // Move to first element
+        cursor.cycle_next();
+
+        assert_eq!(cursor.peek(3).unwrap(), &4);
+
+        cursor.cycle_next();
+        cursor.cycle_next();
+
+        assert_eq!(cursor.peek(3).unwrap(), &2);
+        assert_eq!(cursor.peek(-3).unwrap(), &4);
+    }
+
+    #[test]
+    fn cursor_peek_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.peek(2), None);
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_seek() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.seek(6);
+        assert_eq!(cursor.get().unwrap(), &2);
+
+        cursor.seek(-3);
+        assert_eq!(cursor.get().unwrap(), &3);
+    }
+
+    #[test]
+    fn cursor_seek_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.pos, None);
+        cursor.seek(2);
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_get_undefined_behavior() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.seek(3);
+        let _ = cursor.inner.remove(0);
+        let _ = cursor.inner.remove(0);
+
+        assert_panic(|| cursor.get());
+    }
+
+    #[test]
+    fn cursor_binary_search_seek() {
+        let source = vec![1, 3, 5, 7, 9];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.binary_search_seek(&7), Ok(3));
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &7);
+
+        assert_eq!(cursor.binary_search_seek(&1), Ok(0));
+        assert_eq!(cursor.get().unwrap(), &1);
+    }
+
+    #[test]
+    fn cursor_binary_search_seek_not_found() {
+        let source = vec![1, 3, 5, 7, 9];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.binary_search_seek(&4), Err(2));
+        assert_eq!(cursor.pos, None);
+
+        cursor.cycle_next();
+        assert_eq!(cursor.binary_search_seek(&10), Err(5));
+        assert_eq!(cursor.pos, Some(0));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.binary_search_seek(&1), Err(0));
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_state_hash() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        let initial_hash = cursor.state_hash();
+
+        assert_eq!(cursor.state_hash(), initial_hash);
+
+        cursor.cycle_next();
+        let moved_hash = cursor.state_hash();
+        assert_ne!(moved_hash, initial_hash);
+
+        cursor.cycle_next();
+        assert_ne!(cursor.state_hash(), moved_hash);
+
+        cursor.cycle_prev();
+        assert_eq!(cursor.state_hash(), moved_hash);
+    }
+
+    #[test]
+    fn cursor_state_hash_data_change() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+        let initial_hash = cursor.state_hash();
+
+        cursor.inner[3] = 5;
+        assert_ne!(cursor.state_hash(), initial_hash);
+    }
+
+    #[test]
+    fn cursor_split_off_at_cursor() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let tail = cursor.split_off_at_cursor();
+
+        assert_eq!(cursor.inner, vec![1, 2]);
+        assert_eq!(cursor.pos, None);
+        assert_eq!(tail.inner, vec![3, 4, 5]);
+        assert_eq!(tail.pos, Some(0));
+        assert_eq!(tail.get().unwrap(), &3);
+    }
+
+    #[test]
+    fn cursor_split_off_at_cursor_first_element() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        let tail = cursor.split_off_at_cursor();
+
+        assert!(cursor.inner.is_empty());
+        assert_eq!(cursor.pos, None);
+        assert_eq!(tail.inner, vec![1, 2, 3, 4]);
+        assert_eq!(tail.get().unwrap(), &1);
+    }
+
+    #[test]
+    fn cursor_split_off_at_cursor_none() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        let tail = cursor.split_off_at_cursor();
+
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+        assert!(tail.inner.is_empty());
+        assert_eq!(tail.pos, None);
+    }
+
+    #[test]
+    fn cursor_fold_from_cursor() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        assert_eq!(cursor.fold_from_cursor(0, |acc, item| acc + item), 10);
+
+        let order = cursor.fold_from_cursor(Vec::new(), |mut acc, item| {
+            acc.push(*item);
+            acc
+        });
+        assert_eq!(order, vec![2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn cursor_fold_from_cursor_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.fold_from_cursor(7, |acc, item| acc + item), 7);
+    }
+
+    #[test]
+    fn cursor_try_new() {
+        let cursor = CycleCursor::try_new(vec![1, 2, 3, 4], Some(3)).unwrap();
+
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &4);
+    }
+
+    #[test]
+    fn cursor_try_new_out_of_bounds() {
+        assert_eq!(
+            CycleCursor::try_new(vec![1, 2, 3, 4], Some(4)).unwrap_err(),
+            CursorError::OutOfBounds
+        );
+        assert_eq!(
+            CycleCursor::<usize>::try_new(vec![], Some(0)).unwrap_err(),
+            CursorError::OutOfBounds
+        );
+    }
+
+    #[test]
+    fn cursor_try_new_none_pos() {
+        let cursor = CycleCursor::try_new(vec![1, 2, 3, 4], None).unwrap();
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.get(), None);
+
+        let empty_cursor = CycleCursor::<usize>::try_new(vec![], None).unwrap();
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_group_runs() {
+        let source = vec![1, 3, 2, 4, 6, 5];
+        let cursor = CycleCursor::from(source);
+
+        let runs = cursor.group_runs(|item| item % 2);
+        assert_eq!(runs, vec![vec![&1, &3], vec![&2, &4, &6], vec![&5]]);
+    }
+
+    #[test]
+    fn cursor_group_runs_across_wrap() {
+        let source = vec![1, 3, 2, 4, 6, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        // The trailing odd run is not merged with the leading odd run
+        let runs = cursor.group_runs(|item| item % 2);
+        assert_eq!(runs, vec![vec![&3], vec![&2, &4, &6], vec![&5, &1]]);
+
+        // The run [5, 1, 3] spans the wrap boundary
+        cursor.seek(2);
+        let runs = cursor.group_runs(|item| item % 2);
+        assert_eq!(runs, vec![vec![&4, &6], vec![&5, &1, &3], vec![&2]]);
+    }
+
+    #[test]
+    fn cursor_group_runs_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert!(cursor.group_runs(|item| *item).is_empty());
+    }
+
+    #[test]
+    fn cursor_set_inner() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        cursor.set_inner(vec![5, 6]);
+        assert_eq!(cursor.inner, vec![5, 6]);
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_set_inner_keep_pos() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(1);
+
+        cursor.set_inner_keep_pos(vec![5, 6, 7]);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get().unwrap(), &5);
+
+        cursor.seek(2);
+        cursor.set_inner_keep_pos(vec![8, 9]);
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.get().unwrap(), &9);
+
+        cursor.set_inner_keep_pos(vec![]);
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_enumerate_from_cursor() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        let numbered: Vec<_> = cursor.enumerate_from_cursor().collect();
+        assert_eq!(numbered, vec![(0, &1), (1, &2), (2, &3), (3, &4)]);
+
+        cursor.seek(-1);
+        let numbered: Vec<_> = cursor.enumerate_from_cursor().collect();
+        assert_eq!(numbered.first(), Some(&(0, cursor.get().unwrap())));
+        assert_eq!(numbered, vec![(0, &3), (1, &4), (2, &1), (3, &2)]);
+    }
+
+    #[test]
+    fn cursor_enumerate_from_cursor_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.enumerate_from_cursor().next(), None);
+    }
+
+    #[test]
+    fn cursor_reflect_pos() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        cursor.reflect_pos();
+        assert_eq!(cursor.pos, Some(4));
+        assert_eq!(cursor.get().unwrap(), &5);
+
+        cursor.reflect_pos();
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn cursor_reflect_pos_none() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.reflect_pos();
+        assert_eq!(cursor.pos, None);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.reflect_pos();
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_dedup_all_cursor() {
+        let source = vec![1, 2, 3, 2, 4, 1];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(5);
+
+        cursor.dedup_all_cursor();
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &4);
+    }
+
+    #[test]
+    fn cursor_dedup_all_cursor_removed_duplicate() {
+        let source = vec![1, 2, 3, 2, 4, 1];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on the later duplicate of 2
+        cursor.seek(4);
+        cursor.dedup_all_cursor();
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+        assert_eq!(cursor.get().unwrap(), &2);
+
+        let source = vec![1, 2, 3, 2, 4, 1];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on the later duplicate of 1
+        cursor.cycle_prev();
+        cursor.dedup_all_cursor();
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get().unwrap(), &1);
+    }
+
+    #[test]
+    fn cursor_dedup_all_cursor_none() {
+        let source = vec![1, 1, 1];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.dedup_all_cursor();
+        assert_eq!(cursor.inner, vec![1]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_rotate_to_center() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        cursor.rotate_to_center();
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &6);
+        assert_eq!(cursor.inner, vec![3, 4, 5, 6, 1, 2]);
+    }
+
+    #[test]
+    fn cursor_rotate_to_center_odd_len() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        cursor.rotate_to_center();
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &2);
+        assert_eq!(cursor.inner, vec![6, 7, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn cursor_rotate_to_center_none() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.rotate_to_center();
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_peek_ahead() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None
+        assert_eq!(cursor.peek_ahead(2), vec![&1, &2]);
+
+        cursor.seek(3);
+        assert_eq!(cursor.peek_ahead(0), Vec::<&usize>::new());
+        assert_eq!(cursor.peek_ahead(2), vec![&4, &1]);
+        assert_eq!(cursor.peek_ahead(6), vec![&4, &1, &2, &3, &4, &1]);
+        assert_eq!(cursor.get().unwrap(), &3);
+    }
+
+    #[test]
+    fn cursor_peek_behind() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        assert_eq!(cursor.peek_behind(0), Vec::<&usize>::new());
+        assert_eq!(cursor.peek_behind(2), vec![&1, &4]);
+        assert_eq!(cursor.peek_behind(5), vec![&1, &4, &3, &2, &1]);
+        assert_eq!(cursor.get().unwrap(), &2);
+    }
+
+    #[test]
+    fn cursor_peek_ahead_behind_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert!(cursor.peek_ahead(3).is_empty());
+        assert!(cursor.peek_behind(3).is_empty());
+    }
+
+    #[test]
+    fn cursor_partition_point_from_cursor() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.partition_point_from_cursor(|item| *item < 4), 3);
+        assert_eq!(cursor.partition_point_from_cursor(|item| *item < 10), 6);
+        assert_eq!(cursor.partition_point_from_cursor(|item| *item < 1), 0);
+    }
+
+    #[test]
+    fn cursor_partition_point_from_cursor_wrapped() {
+        // Sorted ring rotated so the sort order wraps past the end
+        let source = vec![4, 5, 6, 1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        assert_eq!(cursor.get().unwrap(), &1);
+        assert_eq!(cursor.partition_point_from_cursor(|item| *item < 3), 2);
+        assert_eq!(cursor.partition_point_from_cursor(|item| *item < 5), 4);
+        assert_eq!(cursor.partition_point_from_cursor(|item| *item <= 6), 6);
+    }
+
+    #[test]
+    fn cursor_partition_point_from_cursor_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.partition_point_from_cursor(|_| true), 0);
+    }
+
+    #[test]
+    fn cursor_into_cycle_iter() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        let items: Vec<_> = cursor.into_cycle_iter().take(7).collect();
+        assert_eq!(items, vec![3, 1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    fn cursor_into_cycle_iter_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.into_cycle_iter().next(), None);
+    }
+
+    #[test]
+    fn cursor_goto_mark() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.mark("unset");
+        cursor.seek(3);
+        cursor.mark("third");
+        cursor.cycle_next();
+
+        assert_eq!(cursor.goto_mark("third"), Ok(()));
+        assert_eq!(cursor.get().unwrap(), &3);
+
+        assert_eq!(cursor.goto_mark("unset"), Ok(()));
+        assert_eq!(cursor.pos, None);
+
+        assert_eq!(cursor.goto_mark("missing"), Err(CursorError::MarkNotFound));
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_goto_mark_truncated() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.cycle_next();
+        cursor.mark("first");
+        cursor.cycle_prev();
+        cursor.mark("last");
+        cursor.inner.truncate(2);
+        cursor.cycle_next();
+
+        assert_eq!(cursor.goto_mark("last"), Err(CursorError::OutOfBounds));
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.list_marks(), vec!["first", "last"]);
+
+        cursor.prune_marks();
+        assert_eq!(cursor.list_marks(), vec!["first"]);
+        assert_eq!(cursor.goto_mark("last"), Err(CursorError::MarkNotFound));
+        assert_eq!(cursor.goto_mark("first"), Ok(()));
+        assert_eq!(cursor.get().unwrap(), &1);
+    }
+
+    #[test]
+    fn cursor_chunks_exact_cyclic() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        let (chunks, remainder) = cursor.chunks_exact_cyclic(3);
+        assert_eq!(chunks.collect::<Vec<_>>(), vec![vec![&7, &1, &2], vec![
+            &3, &4, &5
+        ]]);
+        assert_eq!(remainder, vec![&6]);
+
+        let (chunks, remainder) = cursor.chunks_exact_cyclic(7);
+        assert_eq!(chunks.collect::<Vec<_>>(), vec![vec![
+            &7, &1, &2, &3, &4, &5, &6
+        ]]);
+        assert!(remainder.is_empty());
+
+        let (mut chunks, remainder) = cursor.chunks_exact_cyclic(8);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(remainder.len(), 7);
+    }
+
+    #[test]
+    fn cursor_chunks_exact_cyclic_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        let (mut chunks, remainder) = cursor.chunks_exact_cyclic(2);
+        assert_eq!(chunks.next(), None);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn cursor_chunks_exact_cyclic_zero_size() {
+        let source = vec![1, 2, 3];
+        let cursor = CycleCursor::from(source);
+
+        assert_panic(|| cursor.chunks_exact_cyclic(0));
+    }
+
+    #[test]
+    fn cursor_swap_indices() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        // Cursor at a
+        assert_eq!(cursor.swap_indices(1, 3), Ok(()));
+        assert_eq!(cursor.inner, vec![1, 4, 3, 2]);
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &2);
+
+        // Cursor at b
+        assert_eq!(cursor.swap_indices(0, 3), Ok(()));
+        assert_eq!(cursor.inner, vec![2, 4, 3, 1]);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get().unwrap(), &2);
+
+        // Cursor at neither
+        assert_eq!(cursor.swap_indices(1, 2), Ok(()));
+        assert_eq!(cursor.inner, vec![2, 3, 4, 1]);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get().unwrap(), &2);
+    }
+
+    #[test]
+    fn cursor_swap_indices_out_of_bounds() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        assert_eq!(cursor.swap_indices(0, 4), Err(CursorError::OutOfBounds));
+        assert_eq!(cursor.swap_indices(5, 1), Err(CursorError::OutOfBounds));
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_min_with_offset() {
+        let source = vec![4, 1, 5, 9, 2, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        let (offset, item) = cursor.min_with_offset().unwrap();
+        assert_eq!((offset, item), (-2, &1));
+        assert_eq!(cursor.peek(offset).unwrap(), item);
+        assert_eq!(cursor.get().unwrap(), &9);
+    }
+
+    #[test]
+    fn cursor_max_with_offset() {
+        let source = vec![4, 1, 5, 9, 2, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        let (offset, item) = cursor.max_with_offset().unwrap();
+        assert_eq!((offset, item), (3, &9));
+        assert_eq!(cursor.peek(offset).unwrap(), item);
+        assert_eq!(cursor.get().unwrap(), &4);
+    }
+
+    #[test]
+    fn cursor_min_max_with_offset_ties() {
+        let source = vec![7, 0, 7, 0, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        // Nearest forward occurrences win
+        assert_eq!(cursor.min_with_offset(), Some((1, &0)));
+        assert_eq!(cursor.max_with_offset(), Some((0, &7)));
+    }
+
+    #[test]
+    fn cursor_min_max_with_offset_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.min_with_offset(), None);
+        assert_eq!(cursor.max_with_offset(), None);
+    }
+
+    #[test]
+    fn cursor_current_index() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.current_index(), None);
+        cursor.seek(3);
+        assert_eq!(cursor.current_index(), Some(2));
+
+        let _ = cursor.inner.remove(0);
+        let _ = cursor.inner.remove(0);
+        assert_eq!(cursor.current_index(), None);
+    }
+
+    #[test]
+    fn cursor_current_is() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        assert!(!cursor.current_is(&4));
+        cursor.seek(-1);
+        assert!(cursor.current_is(&3));
+        assert!(!cursor.current_is(&4));
+    }
+
+    #[test]
+    fn cursor_current_is_after_shrink() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        let _ = cursor.inner.pop();
+        assert!(!cursor.current_is(&4));
+        assert!(!cursor.current_is(&3));
+    }
+
+    #[test]
+    fn cursor_offsets_matching() {
+        let source = vec![0, 5, 0, 0, 5, 0, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let offsets = cursor.offsets_matching(|item| *item == 5);
+        assert_eq!(offsets, vec![-1, 2, -3]);
+
+        for offset in offsets {
+            assert_eq!(cursor.peek(offset).unwrap(), &5);
+        }
+    }
+
+    #[test]
+    fn cursor_offsets_matching_ties() {
+        let source = vec![0, 1, 0, 1, 0];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        // Matches at equal distance on both sides, forward first
+        assert_eq!(cursor.get().unwrap(), &0);
+        assert_eq!(cursor.offsets_matching(|item| *item == 1), vec![1, -1]);
+        assert_eq!(cursor.offsets_matching(|item| *item == 0), vec![0, 2, -2]);
+    }
+
+    #[test]
+    fn cursor_offsets_matching_none() {
+        let source = vec![1, 2, 3];
+        let cursor = CycleCursor::from(source);
+        assert!(cursor.offsets_matching(|item| *item > 3).is_empty());
+
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+        assert!(cursor.offsets_matching(|_| true).is_empty());
+    }
+
+    #[test]
+    fn cursor_rotate_cursor_to_index() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        assert_eq!(cursor.rotate_cursor_to_index(0), Ok(()));
+        assert_eq!(cursor.inner, vec![4, 5, 1, 2, 3]);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get().unwrap(), &4);
+
+        assert_eq!(cursor.rotate_cursor_to_index(2), Ok(()));
+        assert_eq!(cursor.inner, vec![2, 3, 4, 5, 1]);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get().unwrap(), &4);
+    }
+
+    #[test]
+    fn cursor_rotate_cursor_to_index_out_of_bounds() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(
+            cursor.rotate_cursor_to_index(1),
+            Err(CursorError::OutOfBounds)
+        );
+
+        cursor.cycle_next();
+        assert_eq!(
+            cursor.rotate_cursor_to_index(3),
+            Err(CursorError::OutOfBounds)
+        );
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_extract_if_cursor() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(5);
+
+        // Pointed element survives
+        let extracted = cursor.extract_if_cursor(|item| item % 2 == 0);
+        assert_eq!(extracted, vec![2, 4, 6]);
+        assert_eq!(cursor.inner, vec![1, 3, 5]);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get().unwrap(), &5);
+    }
+
+    #[test]
+    fn cursor_extract_if_cursor_pointed_extracted() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let extracted = cursor.extract_if_cursor(|item| (2..5).contains(item));
+        assert_eq!(extracted, vec![2, 3, 4]);
+        assert_eq!(cursor.inner, vec![1, 5, 6]);
+        assert_eq!(cursor.get().unwrap(), &5);
+
+        // Wraps to the first survivor
+        cursor.cycle_prev();
+        let extracted = cursor.extract_if_cursor(|item| *item > 1);
+        assert_eq!(extracted, vec![5, 6]);
+        assert_eq!(cursor.get().unwrap(), &1);
+
+        let extracted = cursor.extract_if_cursor(|_| true);
+        assert_eq!(extracted, vec![1]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_extract_if_cursor_none() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.extract_if_cursor(|item| *item == 2), vec![2]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_simulate() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        let moves = [2, -3, 7, 0, -5];
+        let positions = cursor.simulate(&moves);
+        assert_eq!(positions, vec![Some(2), Some(4), Some(1), Some(1), Some(1)]);
+        assert_eq!(cursor.pos, Some(0));
+
+        for (seek_distance, expected) in moves.into_iter().zip(positions) {
+            cursor.seek(seek_distance);
+            assert_eq!(cursor.pos, expected);
+        }
+    }
+
+    #[test]
+    fn cursor_simulate_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.simulate(&[1, -2, 3]), vec![None, None, None]);
+        assert!(cursor.simulate(&[]).is_empty());
+    }
+
+    #[test]
+    fn cursor_steps_since() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+        let bookmark = cursor.bookmark();
+
+        assert_eq!(cursor.steps_since(&bookmark), Some(0));
+
+        // Forward net movement
+        cursor.seek(4);
+        cursor.cycle_prev();
+        assert_eq!(cursor.steps_since(&bookmark), Some(3));
+
+        // Backward net movement across the wrap
+        cursor.seek(-5);
+        assert_eq!(cursor.steps_since(&bookmark), Some(-2));
+    }
+
+    #[test]
+    fn cursor_steps_since_invalid() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        let none_bookmark = cursor.bookmark();
+
+        cursor.cycle_next();
+        assert_eq!(cursor.steps_since(&none_bookmark), None);
+
+        let bookmark = cursor.bookmark();
+        let _ = cursor.inner.pop();
+        assert_eq!(cursor.steps_since(&bookmark), None);
+    }
+
+    #[test]
+    fn cursor_steps_since_stale() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+        cursor.truncate(3);
+
+        // Bookmark taken while the cursor was already stale
+        let stale_bookmark = cursor.bookmark();
+        cursor.set_pos(Some(0)).unwrap();
+        assert_eq!(cursor.steps_since(&stale_bookmark), None);
+
+        // Stale current position against a valid bookmark
+        let bookmark = cursor.bookmark();
+        cursor.pos = Some(7);
+        assert_eq!(cursor.steps_since(&bookmark), None);
+    }
+
+    #[test]
+    fn cursor_flat_map_from_cursor() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        let items: Vec<_> = cursor
+            .flat_map_from_cursor(|item| std::iter::repeat_n(*item, *item))
+            .collect();
+        assert_eq!(items, vec![2, 2, 3, 3, 3, 1]);
+    }
+
+    #[test]
+    fn cursor_flat_map_from_cursor_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.flat_map_from_cursor(|item| vec![*item]).next(), None);
+    }
+
+    #[test]
+    fn cursor_get_autofix() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.set_autofix(true);
+
+        assert_eq!(cursor.get(), None);
+
+        cursor.seek(3);
+        let _ = cursor.inner.remove(0);
+        let _ = cursor.inner.remove(0);
+        assert_eq!(cursor.get().unwrap(), &4);
+
+        cursor.inner.clear();
+        assert_eq!(cursor.get(), None);
+    }
+
+    #[test]
+    fn cursor_get_autofix_disabled() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.set_autofix(true);
+        cursor.set_autofix(false);
+
+        cursor.seek(3);
+        let _ = cursor.inner.remove(0);
+        let _ = cursor.inner.remove(0);
+
+        assert_panic(|| cursor.get());
+    }
+
+    #[test]
+    fn cursor_rotate_subrange() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        // Cursor inside the range
+        assert_eq!(cursor.rotate_subrange(1..6, 2), Ok(()));
+        assert_eq!(cursor.inner, vec![1, 4, 5, 6, 2, 3, 7]);
+        assert_eq!(cursor.pos, Some(5));
+        assert_eq!(cursor.get().unwrap(), &3);
+
+        // Cursor outside the range
+        assert_eq!(cursor.rotate_subrange(0..3, 1), Ok(()));
+        assert_eq!(cursor.inner, vec![4, 5, 1, 6, 2, 3, 7]);
+        assert_eq!(cursor.pos, Some(5));
+        assert_eq!(cursor.get().unwrap(), &3);
+
+        // Full rotation is a no-op
+        assert_eq!(cursor.rotate_subrange(4..7, 3), Ok(()));
+        assert_eq!(cursor.inner, vec![4, 5, 1, 6, 2, 3, 7]);
+        assert_eq!(cursor.pos, Some(5));
+    }
+
+    #[test]
+    fn cursor_rotate_subrange_out_of_bounds() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        assert_eq!(
+            cursor.rotate_subrange(2..5, 1),
+            Err(CursorError::OutOfBounds)
+        );
+        assert_eq!(
+            cursor.rotate_subrange(1..3, 3),
+            Err(CursorError::OutOfBounds)
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 3..1;
+        assert_eq!(
+            cursor.rotate_subrange(reversed, 0),
+            Err(CursorError::OutOfBounds)
+        );
+        assert_eq!(cursor.rotate_subrange(2..2, 0), Ok(()));
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_replace_all() {
+        let source = vec![1, 2, 3, 2, 2];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        assert_eq!(cursor.replace_all(&2, &5), 3);
+        assert_eq!(cursor.inner, vec![1, 5, 3, 5, 5]);
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.get().unwrap(), &5);
+    }
+
+    #[test]
+    fn cursor_replace_all_no_match() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        assert_eq!(cursor.replace_all(&4, &5), 0);
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+        assert_eq!(cursor.get().unwrap(), &3);
+    }
+
+    #[test]
+    fn cursor_round_robin_pairs() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let pairs: Vec<_> = cursor.round_robin_pairs().collect();
+        assert_eq!(pairs.len(), 15);
+
+        // Every round has the cursor element as its first pivot
+        for round in pairs.chunks(3) {
+            assert_eq!(round.first().unwrap().0, &3);
+        }
+
+        let mut normalized: Vec<_> = pairs
+            .iter()
+            .map(|&(first, second)| (*first.min(second), *first.max(second)))
+            .collect();
+        normalized.sort_unstable();
+        normalized.dedup();
+
+        let mut expected = Vec::new();
+        for first in 1..=6 {
+            for second in first + 1..=6 {
+                expected.push((first, second));
+            }
+        }
+        assert_eq!(normalized, expected);
+    }
+
+    #[test]
+    fn cursor_round_robin_pairs_odd_len() {
+        let source = vec![1, 2, 3];
+        let cursor = CycleCursor::from(source);
+
+        let pairs: Vec<_> = cursor.round_robin_pairs().collect();
+        assert_eq!(pairs, vec![(&1, &2), (&1, &3), (&2, &3)]);
+    }
+
+    #[test]
+    fn cursor_round_robin_pairs_small() {
+        let single_cursor = CycleCursor::from(vec![1]);
+        assert_eq!(single_cursor.round_robin_pairs().next(), None);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.round_robin_pairs().next(), None);
+    }
+
+    #[test]
+    fn cursor_vecdeque_round_trip() {
+        let mut deque = VecDeque::from([2, 3, 4]);
+        deque.push_front(1);
+
+        let mut cursor = CycleCursor::from_vecdeque(deque.clone());
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4]);
+        assert_eq!(cursor.pos, None);
+
+        cursor.seek(3);
+        let (round_trip, pos) = cursor.into_vecdeque();
+        assert_eq!(round_trip, deque);
+        assert_eq!(pos, Some(2));
+    }
+
+    #[test]
+    fn cursor_windows_cyclic() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        let windows: Vec<_> = cursor.windows_cyclic(3).collect();
+        assert_eq!(windows, vec![
+            vec![&4, &1, &2],
+            vec![&1, &2, &3],
+            vec![&2, &3, &4],
+            vec![&3, &4, &1],
+        ]);
+    }
+
+    #[test]
+    fn cursor_windows_cyclic_empty() {
+        let source = vec![1, 2, 3];
+        let cursor = CycleCursor::from(source);
+        assert_eq!(cursor.windows_cyclic(0).next(), None);
+
+        let source: Vec<usize> = vec![];
+        let cursor = CycleCursor::from(source);
+        assert_eq!(cursor.windows_cyclic(2).next(), None);
+        assert_eq!(cursor.windows_cyclic_owned(2).next(), None);
+    }
+
+    #[test]
+    fn cursor_windows_cyclic_owned() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let owned: Vec<Vec<usize>> = cursor.windows_cyclic_owned(2).collect();
+        assert_eq!(owned.len(), 5);
+
+        for (owned_window, window) in owned.iter().zip(cursor.windows_cyclic(2)) {
+            assert_eq!(owned_window.iter().collect::<Vec<_>>(), window);
+        }
+    }
+
+    #[test]
+    fn cursor_cycle_period() {
+        let cursor = CycleCursor::from(vec![1, 2, 1, 2]);
+        assert_eq!(cursor.cycle_period(), Some(2));
+
+        let cursor = CycleCursor::from(vec![7, 7, 7]);
+        assert_eq!(cursor.cycle_period(), Some(1));
+
+        let cursor = CycleCursor::from(vec![1, 2, 1, 2, 1]);
+        assert_eq!(cursor.cycle_period(), Some(5));
+    }
+
+    #[test]
+    fn cursor_cycle_period_distinct() {
+        let cursor = CycleCursor::from(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(cursor.cycle_period(), Some(6));
+
+        let cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(cursor.cycle_period(), None);
+    }
+
+    #[test]
+    fn cursor_seek_fractional() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        for _ in 0..7 {
+            let _ = cursor.seek_fractional(0.25);
+        }
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.seek_fractional(0.25), 0.0);
+        assert_eq!(cursor.pos, Some(2));
+
+        // Half steps do not round up
+        assert_eq!(cursor.seek_fractional(0.5), 0.5);
+        assert_eq!(cursor.pos, Some(2));
+    }
+
+    #[test]
+    fn cursor_seek_fractional_backward() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        assert_eq!(cursor.seek_fractional(-0.5), -0.5);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.seek_fractional(-1.75), -0.25);
+        assert_eq!(cursor.pos, Some(3));
+
+        // Opposite movement cancels the carried fraction
+        assert_eq!(cursor.seek_fractional(2.25), 0.0);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_seek_fractional_non_finite() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+        assert_eq!(cursor.seek_fractional(1.25), 0.25);
+
+        assert_eq!(cursor.seek_fractional(f64::NAN), 0.25);
+        assert_eq!(cursor.seek_fractional(f64::INFINITY), 0.25);
+        assert_eq!(cursor.seek_fractional(f64::NEG_INFINITY), 0.25);
+        assert_eq!(cursor.pos, Some(1));
+
+        // Later calls keep working from the preserved remainder
+        assert_eq!(cursor.seek_fractional(0.75), 0.0);
+        assert_eq!(cursor.pos, Some(2));
+    }
+
+    #[test]
+    fn cursor_apply_moves() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7];
+        let moves = [3, -2, 9, -7, 0, 14, -1, 6, -5];
+
+        for initial_pos in [None, Some(0), Some(4)] {
+            let mut batch_cursor = CycleCursor::try_new(source.clone(), initial_pos).unwrap();
+            let mut seek_cursor = batch_cursor.clone();
+
+            batch_cursor.apply_moves(&moves);
+            for seek_distance in moves {
+                seek_cursor.seek(seek_distance);
+            }
+
+            assert_eq!(batch_cursor.pos, seek_cursor.pos);
+        }
+    }
+
+    #[test]
+    fn cursor_apply_moves_empty() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.apply_moves(&[]);
+        assert_eq!(cursor.pos, None);
+
+        let source: Vec<usize> = vec![];
+        let mut cursor = CycleCursor::from(source);
+        cursor.apply_moves(&[1, 2, 3]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_spans_from_cursor() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        let (tail, head) = cursor.spans_from_cursor();
+        assert_eq!(tail, &[4, 5]);
+        assert_eq!(head, &[1, 2, 3]);
+
+        let mut rotated = cursor.inner.clone();
+        rotated.rotate_left(cursor.pos.unwrap());
+        assert_eq!([tail, head].concat(), rotated);
+    }
+
+    #[test]
+    fn cursor_spans_from_cursor_none() {
+        let source = vec![1, 2, 3];
+        let cursor = CycleCursor::from(source);
+
+        let (tail, head) = cursor.spans_from_cursor();
+        assert_eq!(tail, &[1, 2, 3]);
+        assert!(head.is_empty());
+    }
+
+    #[test]
+    fn cursor_retain_mut_cursor() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(5);
+
+        cursor.retain_mut_cursor(|item| {
+            *item += 1;
+            *item % 2 == 0
+        });
+        assert_eq!(cursor.inner, vec![2, 4, 6]);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get().unwrap(), &6);
+    }
+
+    #[test]
+    fn cursor_retain_mut_cursor_pointed_dropped() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        cursor.retain_mut_cursor(|item| {
+            *item *= 2;
+            *item != 8 && *item != 10
+        });
+        assert_eq!(cursor.inner, vec![2, 4, 6, 12]);
+        assert_eq!(cursor.get().unwrap(), &12);
+
+        cursor.retain_mut_cursor(|item| *item < 12);
+        assert_eq!(cursor.inner, vec![2, 4, 6]);
+        assert_eq!(cursor.get().unwrap(), &2);
+    }
+
+    #[test]
+    fn cursor_changed_since() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+        let checkpoint = cursor.checkpoint();
+
+        // Nothing changed
+        assert!(!cursor.changed_since(&checkpoint));
+
+        // Only pos changed
+        cursor.cycle_next();
+        assert!(cursor.changed_since(&checkpoint));
+        cursor.cycle_prev();
+        assert!(!cursor.changed_since(&checkpoint));
+
+        // Only data changed
+        cursor.inner[2] = 5;
+        assert!(cursor.changed_since(&checkpoint));
+        cursor.inner[2] = 3;
+        assert!(!cursor.changed_since(&checkpoint));
+    }
+
+    #[test]
+    fn cursor_intersperse_from_cursor() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_prev();
+
+        let items: Vec<_> = cursor.intersperse_from_cursor(&0).collect();
+        assert_eq!(items, vec![&3, &0, &1, &0, &2]);
+    }
+
+    #[test]
+    fn cursor_intersperse_from_cursor_small() {
+        let cursor = CycleCursor::from(vec![1]);
+        let items: Vec<_> = cursor.intersperse_from_cursor(&0).collect();
+        assert_eq!(items, vec![&1]);
+
+        let cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(cursor.intersperse_from_cursor(&0).next(), None);
+    }
+
+    #[test]
+    fn cursor_shift_values() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let before = *cursor.peek(-2).unwrap();
+        cursor.shift_values(2);
+        assert_eq!(cursor.inner, vec![4, 5, 1, 2, 3]);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get().unwrap(), &before);
+
+        let before = *cursor.peek(3).unwrap();
+        cursor.shift_values(-3);
+        assert_eq!(cursor.inner, vec![2, 3, 4, 5, 1]);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get().unwrap(), &before);
+
+        cursor.shift_values(10);
+        assert_eq!(cursor.inner, vec![2, 3, 4, 5, 1]);
+    }
+
+    #[test]
+    fn cursor_shift_values_empty_vec() {
+        let source: Vec<usize> = vec![];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.shift_values(3);
+        assert!(cursor.inner.is_empty());
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_try_get_mut() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.try_get_mut(), Ok(None));
+
+        cursor.seek(2);
+        *cursor.try_get_mut().unwrap().unwrap() *= 10;
+        assert_eq!(cursor.inner, vec![1, 20, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_try_get_mut_after_shrink() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let _ = cursor.inner.remove(0);
+        let _ = cursor.inner.remove(0);
+        assert_eq!(cursor.try_get_mut(), Err(CursorError::StalePosition));
+    }
+
+    #[test]
+    fn cursor_get_current_mut() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.get_current_mut(), None);
+
+        cursor.cycle_prev();
+        *cursor.get_current_mut().unwrap() = 5;
+        assert_eq!(cursor.get().unwrap(), &5);
+
+        // Indexed access of the underlying vector is still reachable
+        *cursor.get_mut(0).unwrap() = 6;
+        assert_eq!(cursor.inner, vec![6, 2, 3, 5]);
+
+        let _ = cursor.inner.pop();
+        assert_panic(move || cursor.get_current_mut().copied());
+    }
+
+    #[test]
+    fn cursor_get_current_mut_autofix() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.set_autofix(true);
+        cursor.cycle_prev();
+
+        let _ = cursor.inner.pop();
+        *cursor.get_current_mut().unwrap() = 5;
+        assert_eq!(cursor.inner, vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn cursor_find_prev_move() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None starts at the last element
+        assert_eq!(cursor.find_prev_move(|item| item % 3 == 0), Some(&6));
+        assert_eq!(cursor.pos, Some(5));
+
+        assert_eq!(cursor.find_prev_move(|item| item % 3 == 0), Some(&3));
+        assert_eq!(cursor.pos, Some(2));
+
+        // Wraps around past the start
+        assert_eq!(cursor.find_prev_move(|item| *item > 4), Some(&6));
+        assert_eq!(cursor.pos, Some(5));
+    }
+
+    #[test]
+    fn cursor_find_prev_move_only_starting_element() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        assert_eq!(cursor.find_prev_move(|item| *item == 2), Some(&2));
+        assert_eq!(cursor.pos, Some(1));
+    }
+
+    #[test]
+    fn cursor_find_prev_move_no_match() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        assert_eq!(cursor.find_prev_move(|item| *item > 4), None);
+        assert_eq!(cursor.pos, Some(0));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.find_prev_move(|_| true), None);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_same_multiset() {
+        let mut cursor = CycleCursor::from(vec![3, 1, 2, 1]);
+        let mut other = CycleCursor::from(vec![1, 1, 2, 3]);
+        cursor.seek(2);
+        other.seek(-1);
+
+        assert!(cursor.same_multiset(&other));
+        assert!(other.same_multiset(&cursor));
+
+        let empty = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty.same_multiset(&CycleCursor::from(Vec::<usize>::new())));
+        assert!(!empty.same_multiset(&cursor));
+    }
+
+    #[test]
+    fn cursor_same_multiset_multiplicities() {
+        let cursor = CycleCursor::from(vec![1, 1, 2, 3]);
+
+        assert!(!cursor.same_multiset(&CycleCursor::from(vec![1, 2, 2, 3])));
+        assert!(!cursor.same_multiset(&CycleCursor::from(vec![1, 2, 3])));
+        assert!(!cursor.same_multiset(&CycleCursor::from(vec![1, 1, 1, 2, 3])));
+    }
+
+    #[test]
+    fn cursor_seek_clamped() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None starts at the first element
+        assert_eq!(cursor.seek_clamped(2), 2);
+        assert_eq!(cursor.pos, Some(2));
+
+        assert_eq!(cursor.seek_clamped(-1), -1);
+        assert_eq!(cursor.pos, Some(1));
+
+        assert_eq!(cursor.seek_clamped(0), 0);
+        assert_eq!(cursor.pos, Some(1));
+    }
+
+    #[test]
+    fn cursor_seek_clamped_reduces_delta() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        assert_eq!(cursor.seek_clamped(10), 3);
+        assert_eq!(cursor.pos, Some(5));
+        assert_eq!(cursor.seek_clamped(1), 0);
+        assert_eq!(cursor.pos, Some(5));
+
+        assert_eq!(cursor.seek_clamped(isize::MIN), -5);
+        assert_eq!(cursor.pos, Some(0));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.seek_clamped(3), 0);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_take_while_from_cursor() {
+        let source = vec![2, 4, 5, 6, 8];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None starts at the first element
+        let items: Vec<_> = cursor
+            .take_while_from_cursor(|item| item % 2 == 0)
+            .collect();
+        assert_eq!(items, vec![&2, &4]);
+
+        cursor.seek(4);
+        let items: Vec<_> = cursor
+            .take_while_from_cursor(|item| item % 2 == 0)
+            .collect();
+        assert_eq!(items, vec![&6, &8, &2, &4]);
+        assert_eq!(cursor.pos, Some(3));
+
+        assert_eq!(
+            cursor.take_while_from_cursor(|item| *item > 10).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn cursor_take_while_from_cursor_all_match() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        let items: Vec<_> = cursor.take_while_from_cursor(|_| true).collect();
+        assert_eq!(items, vec![&2, &3, &4, &1]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.take_while_from_cursor(|_| true).count(), 0);
+    }
+
+    #[test]
+    fn cursor_history() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        // Disabled by default
+        cursor.cycle_next();
+        assert!(cursor.history().is_empty());
+
+        cursor.enable_history(4);
+        assert_eq!(cursor.history(), &[0]);
+
+        cursor.seek(3);
+        cursor.cycle_prev();
+        assert_eq!(cursor.seek_clamped(10), 3);
+        assert_eq!(cursor.history(), &[0, 3, 2, 5]);
+
+        // Oldest entries are evicted at capacity
+        cursor.reflect_pos();
+        assert_eq!(cursor.history(), &[3, 2, 5, 0]);
+
+        // Structural changes are not movement
+        cursor.rotate_to_center();
+        assert_eq!(cursor.history().len(), 4);
+
+        cursor.enable_history(0);
+        cursor.cycle_next();
+        assert!(cursor.history().is_empty());
+    }
+
+    #[test]
+    fn cursor_history_back() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.enable_history(8);
+
+        cursor.cycle_next();
+        cursor.seek(4);
+        cursor.seek(-2);
+        assert_eq!(cursor.history(), &[0, 4, 2]);
+
+        assert_eq!(cursor.back(), Some(4));
+        assert_eq!(cursor.pos, Some(4));
+        assert_eq!(cursor.back(), Some(0));
+        assert_eq!(cursor.pos, Some(0));
+
+        assert_eq!(cursor.back(), None);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.history(), &[0]);
+    }
+
+    #[test]
+    fn cursor_history_back_skips_invalid() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.enable_history(8);
+
+        cursor.seek(2);
+        cursor.seek(4);
+        cursor.seek(-4);
+        assert_eq!(cursor.history(), &[1, 5, 1]);
+
+        cursor.truncate(3);
+        assert_eq!(cursor.back(), Some(1));
+        assert_eq!(cursor.history(), &[1]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn cursor_random_start() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source.clone());
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..32 {
+            cursor.random_start(&mut rng);
+            assert!(cursor.pos.is_some_and(|pos| pos < 5));
+        }
+        assert_eq!(cursor.inner, source);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.random_start(&mut rng);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn cursor_random_start_history() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        let mut rng = StdRng::seed_from_u64(42);
+        cursor.enable_history(8);
+
+        cursor.random_start(&mut rng);
+        let first = cursor.pos.unwrap();
+        assert_eq!(cursor.history(), &[first]);
+
+        cursor.random_start(&mut rng);
+        let second = cursor.pos.unwrap();
+        assert_eq!(cursor.history(), &[first, second]);
+
+        assert_eq!(cursor.back(), Some(first));
+        assert_eq!(cursor.pos, Some(first));
+    }
+
+    #[test]
+    fn cursor_neighbors_mut() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        assert!(cursor.neighbors_mut().is_none());
+
+        cursor.seek(3);
+        let (prev, current, next) = cursor.neighbors_mut().unwrap();
+        *prev *= 10;
+        *current *= 100;
+        *next *= 1000;
+        assert_eq!(cursor.inner, vec![1, 20, 300, 4000, 5]);
+
+        // Neighbors wrap around the ends
+        cursor.seek(2);
+        let (prev, current, next) = cursor.neighbors_mut().unwrap();
+        *prev = 0;
+        *current = 0;
+        *next = 0;
+        assert_eq!(cursor.inner, vec![0, 20, 300, 0, 0]);
+
+        cursor.cycle_next();
+        let (prev, current, next) = cursor.neighbors_mut().unwrap();
+        *prev = 7;
+        *current = 8;
+        *next = 9;
+        assert_eq!(cursor.inner, vec![8, 9, 300, 0, 7]);
+    }
+
+    #[test]
+    fn cursor_neighbors_mut_too_short() {
+        let source = vec![1, 2];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+        assert!(cursor.neighbors_mut().is_none());
+
+        let mut cursor = CycleCursor::from(vec![1, 2, 3]);
+        cursor.cycle_next();
+        assert!(cursor.neighbors_mut().is_some());
+
+        cursor.seek(2);
+        cursor.truncate(2);
+        assert!(cursor.neighbors_mut().is_none());
+    }
+
+    #[test]
+    fn cursor_compact() {
+        let source = vec![None, Some(1), None, Some(2), Some(3), None];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        cursor.compact();
+        assert_eq!(cursor.inner, vec![Some(1), Some(2), Some(3)]);
+        assert_eq!(cursor.get(), Some(&Some(2)));
+
+        let mut cursor = CycleCursor::from(vec![Some(1), None]);
+        cursor.compact();
+        assert_eq!(cursor.inner, vec![Some(1)]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_compact_removes_pointed() {
+        let source = vec![Some(1), None, Some(2), None, None];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        cursor.compact();
+        assert_eq!(cursor.inner, vec![Some(1), Some(2)]);
+        assert_eq!(cursor.get(), Some(&Some(2)));
+
+        // Wraps to the first remaining element
+        let mut cursor = CycleCursor::from(vec![Some(1), Some(2), None]);
+        cursor.seek(3);
+        cursor.compact();
+        assert_eq!(cursor.get(), Some(&Some(1)));
+
+        let mut cursor = CycleCursor::from(vec![None::<usize>, None]);
+        cursor.cycle_next();
+        cursor.compact();
+        assert!(cursor.inner.is_empty());
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_seek_to_fraction() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.seek_to_fraction(0.0);
+        assert_eq!(cursor.pos, Some(0));
+        cursor.seek_to_fraction(0.5);
+        assert_eq!(cursor.pos, Some(2));
+        cursor.seek_to_fraction(1.0);
+        assert_eq!(cursor.pos, Some(4));
+
+        cursor.seek_to_fraction(0.3);
+        assert_eq!(cursor.pos, Some(1));
+        cursor.seek_to_fraction(-1.0);
+        assert_eq!(cursor.pos, Some(0));
+        cursor.seek_to_fraction(f64::INFINITY);
+        assert_eq!(cursor.pos, Some(4));
+        cursor.seek_to_fraction(f64::NAN);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_seek_to_fraction_midpoint() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        // 0.5 * 3 = 1.5 rounds up to the later element
+        cursor.seek_to_fraction(0.5);
+        assert_eq!(cursor.pos, Some(2));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.seek_to_fraction(0.5);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_chain_iter() {
+        let mut cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+        let mut other = CycleCursor::from(vec![5, 6, 7]);
+        cursor.seek(3);
+        other.seek(-1);
+
+        let items: Vec<_> = cursor.chain_iter(&other).collect();
+        assert_eq!(items, vec![&3, &4, &1, &2, &6, &7, &5]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        let items: Vec<_> = empty_cursor.chain_iter(&other).collect();
+        assert_eq!(items, vec![&6, &7, &5]);
+        let items: Vec<_> = cursor.chain_iter(&empty_cursor).collect();
+        assert_eq!(items, vec![&3, &4, &1, &2]);
+        assert_eq!(empty_cursor.chain_iter(&empty_cursor).next(), None);
+    }
+
+    #[test]
+    fn cursor_seek_to_nearest() {
+        let source = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None measures from the first element
+        assert_eq!(cursor.seek_to_nearest(&[5, 8]), Some(8));
+        assert_eq!(cursor.pos, Some(8));
 
         cursor.seek(-3);
-        assert_eq!(cursor.get().unwrap(), &3);
+        assert_eq!(cursor.seek_to_nearest(&[1, 8, 6]), Some(6));
+        assert_eq!(cursor.seek_to_nearest(&[2, 9]), Some(9));
+
+        // Candidates straddling the cursor across the wrap
+        assert_eq!(cursor.seek_to_nearest(&[7, 2]), Some(7));
+        assert_eq!(cursor.seek_to_nearest(&[3, 2]), Some(3));
+    }
+
+    #[test]
+    fn cursor_seek_to_nearest_ties_and_misses() {
+        let source = vec![0, 1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        assert_eq!(cursor.seek_to_nearest(&[0, 4]), Some(4));
+        assert_eq!(cursor.seek_to_nearest(&[1, 1]), Some(1));
+        assert_eq!(cursor.seek_to_nearest(&[4, 10, 5]), Some(5));
+
+        assert_eq!(cursor.seek_to_nearest(&[]), None);
+        assert_eq!(cursor.seek_to_nearest(&[6, 100]), None);
+        assert_eq!(cursor.pos, Some(5));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.seek_to_nearest(&[0]), None);
+    }
+
+    #[test]
+    fn cursor_map_windows_cyclic() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let sums = cursor.map_windows_cyclic(3, |window| window.iter().copied().sum::<usize>());
+        assert_eq!(sums, vec![12, 10, 8, 6, 9]);
+
+        let sizes = cursor.map_windows_cyclic(7, |window| window.len());
+        assert_eq!(sizes, vec![7; 5]);
+
+        assert!(
+            cursor
+                .map_windows_cyclic(0, |window| window.len())
+                .is_empty()
+        );
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(
+            empty_cursor
+                .map_windows_cyclic(2, |window| window.len())
+                .is_empty()
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn cursor_to_json() {
+        let source = vec![4, -2, 7];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.to_json(), r#"{"inner":[4,-2,7],"pos":null}"#);
+
+        cursor.seek(2);
+        assert_eq!(cursor.to_json(), r#"{"inner":[4,-2,7],"pos":1}"#);
+        assert_eq!(cursor.pos_json(), r#"{"pos":1}"#);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.to_json(), r#"{"inner":[],"pos":null}"#);
+        assert_eq!(empty_cursor.pos_json(), r#"{"pos":null}"#);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn cursor_to_json_strings() {
+        let source = vec![
+            "a",
+            "b\"c",
+            "back\\slash",
+            "line\nbreak\u{1}",
+            "42",
+            "true",
+            "01",
+        ];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+        assert_eq!(
+            cursor.to_json(),
+            r#"{"inner":["a","b\"c","back\\slash","line\nbreak\u0001",42,true,"01"],"pos":1}"#
+        );
+
+        let source = vec![-1.5, 2e-7, f64::NAN, f64::INFINITY];
+        let cursor = CycleCursor::from(source);
+        assert_eq!(
+            cursor.to_json(),
+            r#"{"inner":[-1.5,0.0000002,"NaN","inf"],"pos":null}"#
+        );
+    }
+
+    #[test]
+    fn cursor_canonicalize() {
+        let source = vec![2, 0, 1, 0, 0, 3];
+        let expected = vec![0, 0, 3, 2, 0, 1];
+
+        for shift in 0..source.len() {
+            let mut rotated = source.clone();
+            rotated.rotate_left(shift);
+
+            let mut cursor = CycleCursor::from(rotated);
+            cursor.seek(4);
+            cursor.canonicalize();
+
+            assert_eq!(cursor.inner, expected);
+            assert_eq!(cursor.pos, Some(0));
+        }
+    }
+
+    #[test]
+    fn cursor_canonicalize_repeating() {
+        let mut cursor = CycleCursor::from(vec![1, 2, 1, 2]);
+        cursor.canonicalize();
+        assert_eq!(cursor.inner, vec![1, 2, 1, 2]);
+
+        let mut cursor = CycleCursor::from(vec![5, 5, 5]);
+        cursor.canonicalize();
+        assert_eq!(cursor.inner, vec![5, 5, 5]);
+        assert_eq!(cursor.pos, Some(0));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.canonicalize();
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_iter_cycle_rev() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.pos = Some(0);
+
+        let items: Vec<_> = cursor.iter_cycle_rev().take(4).collect();
+        assert_eq!(items, vec![&1, &3, &2, &1]);
+
+        cursor.seek(2);
+        let items: Vec<_> = cursor.iter_cycle_rev().take(7).collect();
+        assert_eq!(items, vec![&3, &2, &1, &3, &2, &1, &3]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.iter_cycle_rev().next(), None);
+    }
+
+    #[test]
+    fn cursor_at() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        assert_eq!(cursor.at(0), Some(&1));
+        assert_eq!(cursor.at(3), Some(&4));
+        assert_eq!(cursor.at(4), None);
+        assert_eq!(cursor.at(usize::MAX), None);
+
+        *cursor.at_mut(0).unwrap() = 10;
+        assert_eq!(cursor.inner, vec![10, 2, 3, 4]);
+        assert_eq!(cursor.at_mut(4), None);
+        assert_eq!(cursor.pos, Some(2));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.at(0), None);
+        assert_eq!(empty_cursor.at_mut(0), None);
+    }
+
+    #[test]
+    fn cursor_matching_mask() {
+        let source = vec![5, 8, 13, 21, 34];
+        let cursor = CycleCursor::from(source);
+
+        let mask = cursor.matching_mask(|item| item % 2 == 1);
+        assert_eq!(mask, vec![true, false, true, true, false]);
+        for (item, is_set) in cursor.iter().zip(&mask) {
+            assert_eq!(item % 2 == 1, *is_set);
+        }
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty_cursor.matching_mask(|_| true).is_empty());
+    }
+
+    #[test]
+    fn cursor_cycle_next_where_mask() {
+        let source = vec![5, 8, 13, 21, 34];
+        let mut cursor = CycleCursor::from(source);
+        let mask = cursor.matching_mask(|item| *item > 10);
+
+        assert_eq!(cursor.cycle_next_where_mask(&mask), Some(&13));
+        assert_eq!(cursor.cycle_next_where_mask(&mask), Some(&21));
+        assert_eq!(cursor.cycle_next_where_mask(&mask), Some(&34));
+        assert_eq!(cursor.cycle_next_where_mask(&mask), Some(&13));
+        assert_eq!(cursor.pos, Some(2));
+
+        // Only the pointed position is set
+        assert_eq!(
+            cursor.cycle_next_where_mask(&[false, false, true]),
+            Some(&13)
+        );
+        assert_eq!(cursor.pos, Some(2));
+
+        // Short masks treat the missing positions as unset
+        assert_eq!(cursor.cycle_next_where_mask(&[true]), Some(&5));
+        assert_eq!(cursor.cycle_next_where_mask(&[false; 5]), None);
+        assert_eq!(cursor.pos, Some(0));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.cycle_next_where_mask(&[true]), None);
+    }
+
+    #[test]
+    fn cursor_find_next_within() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None starts at the first element
+        assert_eq!(cursor.find_next_within(1, |item| *item == 1), Some(&1));
+        assert_eq!(cursor.pos, Some(0));
+
+        // Just outside the radius
+        assert_eq!(cursor.find_next_within(3, |item| *item == 5), None);
+        assert_eq!(cursor.pos, Some(0));
+
+        // Just inside the radius
+        assert_eq!(cursor.find_next_within(4, |item| *item == 5), Some(&5));
+        assert_eq!(cursor.pos, Some(4));
+
+        // Wraps around, reaching the pointed element only on a full wrap
+        assert_eq!(cursor.find_next_within(3, |item| *item == 1), Some(&1));
+        assert_eq!(cursor.find_next_within(5, |item| *item == 1), None);
+        assert_eq!(cursor.find_next_within(100, |item| *item == 1), Some(&1));
+
+        assert_eq!(cursor.find_next_within(0, |_| true), None);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_find_prev_within() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None starts at the last element
+        assert_eq!(cursor.find_prev_within(1, |item| *item == 6), Some(&6));
+        assert_eq!(cursor.pos, Some(5));
+
+        // Just outside the radius
+        assert_eq!(cursor.find_prev_within(2, |item| *item == 3), None);
+        assert_eq!(cursor.pos, Some(5));
+
+        // Just inside the radius
+        assert_eq!(cursor.find_prev_within(3, |item| *item == 3), Some(&3));
+        assert_eq!(cursor.pos, Some(2));
+
+        // Wraps around the start
+        assert_eq!(cursor.find_prev_within(3, |item| *item == 6), Some(&6));
+        assert_eq!(cursor.pos, Some(5));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.find_prev_within(3, |_| true), None);
+        assert_eq!(empty_cursor.find_next_within(3, |_| true), None);
+    }
+
+    #[test]
+    fn cursor_scroll() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        cursor.scroll(2);
+        assert_eq!(cursor.inner, vec![3, 4, 5, 6, 1, 2]);
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get(), Some(&6));
+
+        cursor.scroll(-1);
+        assert_eq!(cursor.inner, vec![2, 3, 4, 5, 6, 1]);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get(), Some(&4));
+
+        // Full turns leave everything in place
+        cursor.scroll(6);
+        assert_eq!(cursor.inner, vec![2, 3, 4, 5, 6, 1]);
+        assert_eq!(cursor.get(), Some(&4));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.scroll(2);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_scroll_direction() {
+        let source = vec![1, 2, 3, 4, 5];
+
+        // A positive scroll moves the elements opposite to shift_values
+        let mut scrolled = CycleCursor::from(source.clone());
+        scrolled.scroll(1);
+        assert_eq!(scrolled.inner, vec![2, 3, 4, 5, 1]);
+
+        let mut shifted = CycleCursor::from(source);
+        shifted.shift_values(1);
+        assert_eq!(shifted.inner, vec![5, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_try_fold_from_cursor() {
+        let source = vec![3, 5, -1, 7, 2];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        let mut visited = Vec::new();
+        let result = cursor.try_fold_from_cursor(0, |acc, &item| {
+            visited.push(item);
+            if item < 0 { Err(acc) } else { Ok(acc + item) }
+        });
+        assert_eq!(result, Err(17));
+        assert_eq!(visited, vec![7, 2, 3, 5, -1]);
+
+        let result = cursor.try_fold_from_cursor(0, |acc, &item| Ok::<_, ()>(acc + item));
+        assert_eq!(result, Ok(16));
+
+        let empty_cursor = CycleCursor::from(Vec::<i32>::new());
+        let result = empty_cursor.try_fold_from_cursor(5, |_, _| Err(()));
+        assert_eq!(result, Ok(5));
+    }
+
+    #[test]
+    fn cursor_replace_inner_keep_selection() {
+        let source = vec![10, 20, 30, 20];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        // Selection persists at the first occurrence
+        cursor.replace_inner_keep_selection(vec![5, 20, 25, 20]);
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.get(), Some(&20));
+
+        cursor.replace_inner_keep_selection(vec![20]);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_replace_inner_keep_selection_disappears() {
+        let source = vec![10, 20, 30];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        cursor.replace_inner_keep_selection(vec![10, 20, 40]);
+        assert_eq!(cursor.pos, None);
+        assert_eq!(cursor.inner, vec![10, 20, 40]);
+
+        // Nothing is selected on a cursor pointing to None
+        cursor.replace_inner_keep_selection(vec![10]);
+        assert_eq!(cursor.pos, None);
+
+        cursor.cycle_next();
+        cursor.replace_inner_keep_selection(Vec::new());
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_dedup_within_cursor() {
+        // Duplicates inside the window are removed, those outside are kept
+        let source = vec![1, 2, 1, 3, 4, 5, 1, 1];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(6);
+
+        cursor.dedup_within_cursor(3);
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4, 5, 1]);
+        assert_eq!(cursor.get(), Some(&5));
+
+        let mut cursor = CycleCursor::from(vec![7, 7, 7, 7]);
+        cursor.dedup_within_cursor(0);
+        assert_eq!(cursor.inner, vec![7, 7, 7, 7]);
+        cursor.dedup_within_cursor(1);
+        assert_eq!(cursor.inner, vec![7]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_dedup_within_cursor_relocates() {
+        let source = vec![1, 2, 2, 3, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+
+        // Pointing at a removed duplicate lands on the kept copy
+        cursor.seek(5);
+        cursor.dedup_within_cursor(2);
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.get(), Some(&2));
+
+        // Windows compare against removed elements too, chaining to the kept copy
+        let mut cursor = CycleCursor::from(vec![4, 4, 4, 5]);
+        cursor.seek(3);
+        cursor.dedup_within_cursor(1);
+        assert_eq!(cursor.inner, vec![4, 5]);
+        assert_eq!(cursor.pos, Some(0));
+    }
+
+    #[test]
+    fn cursor_progress() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.progress(), None);
+
+        cursor.cycle_next();
+        assert_eq!(cursor.progress(), Some(0.0));
+        cursor.seek(2);
+        assert_eq!(cursor.progress(), Some(0.5));
+        cursor.seek(2);
+        assert_eq!(cursor.progress(), Some(1.0));
+
+        for frac in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            cursor.seek_to_fraction(frac);
+            assert_eq!(cursor.progress(), Some(frac));
+        }
+
+        cursor.truncate(2);
+        assert_eq!(cursor.progress(), None);
+    }
+
+    #[test]
+    fn cursor_progress_single() {
+        let mut cursor = CycleCursor::from(vec![1]);
+        cursor.cycle_next();
+        assert_eq!(cursor.progress(), Some(0.0));
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.progress(), None);
+    }
+
+    #[test]
+    fn cursor_sliding_max_cyclic() {
+        let source = vec![4, 1, 7, 3, 3, 0, 9, 2, 5];
+        let mut cursor = CycleCursor::from(source);
+
+        for pos in [None, Some(0), Some(4), Some(8)] {
+            cursor.pos = pos;
+            for window in 1..=12 {
+                let naive =
+                    cursor.map_windows_cyclic(window, |items| **items.iter().max().unwrap());
+                let maxima: Vec<_> = cursor
+                    .sliding_max_cyclic(window)
+                    .into_iter()
+                    .copied()
+                    .collect();
+                assert_eq!(maxima, naive);
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_sliding_max_cyclic_wrap() {
+        let source = vec![5, 1, 2, 3, 0];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        // Windows crossing the end pick up the leading 5
+        assert_eq!(cursor.sliding_max_cyclic(3), vec![&3, &5, &5, &5, &3]);
+        assert_eq!(cursor.sliding_max_cyclic(1), vec![&2, &3, &0, &5, &1]);
+        assert!(cursor.sliding_max_cyclic(0).is_empty());
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty_cursor.sliding_max_cyclic(2).is_empty());
+    }
+
+    #[test]
+    fn cursor_normalize_pos() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+
+        // Pointing to None
+        cursor.normalize_pos();
+        assert_eq!(cursor.pos, Some(0));
+
+        // Already valid
+        cursor.seek(2);
+        cursor.normalize_pos();
+        assert_eq!(cursor.pos, Some(2));
+        cursor.normalize_pos();
+        assert_eq!(cursor.pos, Some(2));
+
+        // Stale
+        cursor.truncate(1);
+        cursor.normalize_pos();
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get(), Some(&1));
+
+        cursor.clear();
+        cursor.normalize_pos();
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_normalize_pos_notifies() {
+        let moves = Arc::new(Mutex::new(Vec::new()));
+        let mut cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+        cursor.enable_history(4);
+
+        let recorded = Arc::clone(&moves);
+        cursor.set_on_move(move |old, new| recorded.lock().unwrap().push((old, new)));
+
+        cursor.normalize_pos();
+        assert_eq!(cursor.history(), &[0]);
+        assert_eq!(*moves.lock().unwrap(), vec![(None, Some(0))]);
+
+        // Valid and clamped positions are not reported as moves
+        cursor.normalize_pos();
+        cursor.seek(-1);
+        cursor.truncate(2);
+        cursor.normalize_pos();
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.history(), &[0, 3]);
+        assert_eq!(*moves.lock().unwrap(), vec![
+            (None, Some(0)),
+            (Some(0), Some(3))
+        ]);
+    }
+
+    #[test]
+    fn cursor_diff_from_cursor() {
+        let mut cursor = CycleCursor::from(vec![1, 2, 3, 4, 5]);
+        let mut other = CycleCursor::from(vec![4, 5, 1, 2, 3]);
+        cursor.seek(2);
+        other.seek(4);
+        assert!(cursor.diff_from_cursor(&other).is_empty());
+
+        other.inner[4] = 30;
+        assert_eq!(cursor.diff_from_cursor(&other), vec![(1, &3, &30)]);
+        assert_eq!(other.diff_from_cursor(&cursor), vec![(1, &30, &3)]);
+    }
+
+    #[test]
+    fn cursor_diff_from_cursor_unequal_lengths() {
+        let cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+        let other = CycleCursor::from(vec![1, 0]);
+
+        assert_eq!(cursor.diff_from_cursor(&other), vec![(1, &2, &0)]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(
+            empty_cursor
+                .diff_from_cursor(&CycleCursor::from(vec![1]))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn cursor_sort_by_key_cursor() {
+        let source = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e')];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        // Cursor on the second of three equal keys
+        cursor.sort_by_key_cursor(|&(key, _)| key);
+        assert_eq!(cursor.inner, vec![
+            (1, 'b'),
+            (1, 'd'),
+            (2, 'a'),
+            (2, 'c'),
+            (2, 'e')
+        ]);
+        assert_eq!(cursor.get(), Some(&(2, 'c')));
+        assert_eq!(cursor.pos, Some(3));
+
+        cursor.sort_by_key_cursor(|&(key, _)| Reverse(key));
+        assert_eq!(cursor.get(), Some(&(2, 'c')));
+        assert_eq!(cursor.pos, Some(1));
+    }
+
+    #[test]
+    fn cursor_sort_by_key_cursor_no_pos() {
+        let source = vec![3, 1, 2];
+        let mut cursor = CycleCursor::from(source);
+
+        cursor.sort_by_key_cursor(|item| *item);
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_remaining() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.remaining_forward(), None);
+        assert_eq!(cursor.remaining_backward(), None);
+
+        cursor.cycle_next();
+        assert_eq!(cursor.remaining_forward(), Some(4));
+        assert_eq!(cursor.remaining_backward(), Some(0));
+
+        cursor.seek(2);
+        assert_eq!(cursor.remaining_forward(), Some(2));
+        assert_eq!(cursor.remaining_backward(), Some(2));
+
+        cursor.seek(2);
+        assert_eq!(cursor.remaining_forward(), Some(0));
+        assert_eq!(cursor.remaining_backward(), Some(4));
+
+        // Counts do not wrap around
+        cursor.cycle_next();
+        assert_eq!(cursor.remaining_forward(), Some(4));
+        assert_eq!(cursor.remaining_backward(), Some(0));
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.remaining_forward(), None);
+        assert_eq!(empty_cursor.remaining_backward(), None);
+    }
+
+    #[test]
+    fn cursor_split_at_offset() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(5);
+
+        let (head, rest) = cursor.clone().split_at_offset(3);
+        assert_eq!(head.inner, vec![5, 6, 1]);
+        assert_eq!(rest.inner, vec![2, 3, 4]);
+        assert_eq!(head.get(), Some(&5));
+        assert_eq!(rest.get(), Some(&2));
+
+        let (head, rest) = cursor.clone().split_at_offset(-1);
+        assert_eq!(head.inner, vec![5, 6, 1, 2, 3]);
+        assert_eq!(rest.inner, vec![4]);
+
+        let (head, rest) = cursor.split_at_offset(0);
+        assert!(head.inner.is_empty());
+        assert_eq!(head.pos, None);
+        assert_eq!(rest.inner, vec![5, 6, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_split_at_offset_no_pos() {
+        let source = vec![1, 2, 3, 4];
+        let cursor = CycleCursor::from(source);
+
+        // Cursor on None splits from the first element
+        let (head, rest) = cursor.split_at_offset(-3);
+        assert_eq!(head.inner, vec![1]);
+        assert_eq!(rest.inner, vec![2, 3, 4]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        let (head, rest) = empty_cursor.split_at_offset(2);
+        assert!(head.inner.is_empty() && rest.inner.is_empty());
+    }
+
+    #[test]
+    fn cursor_set_pos() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.set_pos(Some(1)), Ok(()));
+        assert_eq!(cursor.get(), Some(&2));
+        assert_eq!(cursor.set_pos(Some(3)), Err(CursorError::OutOfBounds));
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.set_pos(None), Ok(()));
+        assert_eq!(cursor.pos, None);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.set_pos(Some(0)), Err(CursorError::OutOfBounds));
+    }
+
+    #[test]
+    fn cursor_on_move() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        let moves = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&moves);
+        cursor.set_on_move(move |old, new| recorded.lock().unwrap().push((old, new)));
+
+        cursor.cycle_next();
+        cursor.seek(2);
+        cursor.seek(4);
+        cursor.cycle_prev();
+        cursor.set_pos(Some(1)).unwrap();
+        cursor.set_pos(Some(1)).unwrap();
+        cursor.set_pos(None).unwrap();
+
+        assert_eq!(*moves.lock().unwrap(), vec![
+            (None, Some(0)),
+            (Some(0), Some(2)),
+            (Some(2), Some(1)),
+            (Some(1), None),
+        ]);
+    }
+
+    #[test]
+    fn cursor_on_move_no_op() {
+        let count = Arc::new(Mutex::new(0));
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+
+        let counter = Arc::clone(&count);
+        empty_cursor.set_on_move(move |_, _| *counter.lock().unwrap() += 1);
+
+        empty_cursor.cycle_next();
+        empty_cursor.cycle_prev();
+        empty_cursor.seek(3);
+        assert_eq!(*count.lock().unwrap(), 0);
+
+        let mut cursor = CycleCursor::from(vec![1]);
+        let counter = Arc::clone(&count);
+        cursor.set_on_move(move |_, _| *counter.lock().unwrap() += 1);
+
+        cursor.cycle_next();
+        cursor.cycle_next();
+        cursor.seek(5);
+        assert_eq!(*count.lock().unwrap(), 1);
+
+        // Clones and cleared cursors do not notify
+        let mut cloned = cursor.clone();
+        cloned.set_pos(None).unwrap();
+        cursor.clear_on_move();
+        cursor.set_pos(None).unwrap();
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn cursor_on_move_panicking_callback() {
+        let count = Arc::new(Mutex::new(0));
+        let mut cursor = CycleCursor::from(vec![1, 2, 3]);
+
+        let counter = Arc::clone(&count);
+        cursor.set_on_move(move |_, _| {
+            *counter.lock().unwrap() += 1;
+            panic!("Callback failure");
+        });
+
+        let panic_hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| cursor.cycle_next()));
+        panic::set_hook(panic_hook);
+        assert!(result.is_err());
+
+        // The panicking callback is dropped instead of being invoked again
+        cursor.cycle_next();
+        assert_eq!(*count.lock().unwrap(), 1);
+        assert_eq!(cursor.pos, Some(1));
+    }
+
+    #[test]
+    fn cursor_on_move_across_threads() {
+        let count = Arc::new(Mutex::new(0));
+        let mut cursor = CycleCursor::from(vec![1, 2, 3]);
+
+        let counter = Arc::clone(&count);
+        cursor.set_on_move(move |_, _| *counter.lock().unwrap() += 1);
+
+        let cursor = std::thread::spawn(move || {
+            cursor.cycle_next();
+            cursor
+        })
+        .join()
+        .unwrap();
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn cursor_rotate_to_nearest() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.cycle_next();
+
+        // Nearest match lies backward from the cursor at the first element
+        assert!(cursor.rotate_to_nearest(|item| *item == 3 || *item == 7));
+        assert_eq!(cursor.inner, vec![7, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(cursor.get(), Some(&1));
+
+        // Forward wins when equally far
+        let mut cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+        assert!(cursor.rotate_to_nearest(|item| *item == 3));
+        assert_eq!(cursor.inner, vec![3, 4, 1, 2]);
+        assert_eq!(cursor.pos, None);
+
+        // Already satisfied
+        assert!(cursor.rotate_to_nearest(|item| *item > 2));
+        assert_eq!(cursor.inner, vec![3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn cursor_rotate_to_nearest_no_match() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        assert!(!cursor.rotate_to_nearest(|item| *item > 3));
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+        assert_eq!(cursor.pos, Some(1));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(!empty_cursor.rotate_to_nearest(|_| true));
+    }
+
+    #[test]
+    fn cursor_count_distinct() {
+        let source = vec!['b', 'a', 'b', 'c', 'a', 'a', 'd'];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.count_distinct(), 4);
+        assert_eq!(cursor.distinct_values(), vec![&'b', &'a', &'c', &'d']);
+
+        // Independent of the cursor position
+        cursor.seek(4);
+        assert_eq!(cursor.count_distinct(), 4);
+        assert_eq!(cursor.distinct_values(), vec![&'b', &'a', &'c', &'d']);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.count_distinct(), 0);
+        assert!(empty_cursor.distinct_values().is_empty());
+    }
+
+    #[test]
+    fn cursor_seek_get_mut() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+
+        *cursor.seek_get_mut(3).unwrap() += 30;
+        assert_eq!(cursor.get(), Some(&33));
+
+        *cursor.seek_get_mut(4).unwrap() = 0;
+        assert_eq!(cursor.get(), Some(&0));
+        assert_eq!(cursor.inner, vec![1, 0, 33, 4, 5]);
+
+        *cursor.seek_get_mut(-2).unwrap() = 7;
+        assert_eq!(cursor.inner, vec![1, 0, 33, 4, 7]);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.seek_get_mut(1), None);
+    }
+
+    #[test]
+    fn cursor_align_with() {
+        let reference = CycleCursor::from(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        let mut cursor = CycleCursor::from(vec![5, 6, 9, 0, 1, 2, 9, 4]);
+        cursor.seek(2);
+
+        // Best rotation matches six elements, despite the noise
+        assert!(cursor.align_with(&reference));
+        assert_eq!(cursor.inner, vec![0, 1, 2, 9, 4, 5, 6, 9]);
+        assert_eq!(cursor.get(), Some(&6));
+        assert_eq!(cursor.pos, Some(6));
+
+        // Already aligned
+        assert!(cursor.align_with(&reference));
+        assert_eq!(cursor.inner, vec![0, 1, 2, 9, 4, 5, 6, 9]);
+    }
+
+    #[test]
+    fn cursor_align_with_length_mismatch() {
+        let reference = CycleCursor::from(vec![1, 2, 3]);
+        let mut cursor = CycleCursor::from(vec![3, 1]);
+
+        assert!(!cursor.align_with(&reference));
+        assert_eq!(cursor.inner, vec![3, 1]);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty_cursor.align_with(&CycleCursor::from(Vec::new())));
+    }
+
+    #[test]
+    fn cursor_decimate() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(5);
+
+        assert_eq!(cursor.decimate(2), vec![2, 4, 6, 8]);
+        assert_eq!(cursor.inner, vec![1, 3, 5, 7, 9]);
+        assert_eq!(cursor.get(), Some(&5));
+
+        assert_eq!(cursor.decimate(1), vec![1, 3, 5, 7, 9]);
+        assert!(cursor.inner.is_empty());
+        assert_eq!(cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_decimate_pointed() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        // Pointed element is removed, cursor moves to the next survivor
+        cursor.seek(4);
+        assert_eq!(cursor.decimate(4), vec![4]);
+        assert_eq!(cursor.get(), Some(&5));
+
+        // Wraps to the first survivor
+        cursor.cycle_next();
+        assert_eq!(cursor.decimate(5), vec![6]);
+        assert_eq!(cursor.inner, vec![1, 2, 3, 5]);
+        assert_eq!(cursor.get(), Some(&1));
+
+        assert_eq!(cursor.decimate(10), Vec::<usize>::new());
+        assert_eq!(cursor.inner, vec![1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn cursor_decimate_zero() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_panic(move || cursor.decimate(0));
+    }
+
+    #[test]
+    fn cursor_pairs_at_distance() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+
+        let pairs: Vec<_> = cursor.pairs_at_distance(2).collect();
+        assert_eq!(pairs, vec![
+            (&1, &3),
+            (&2, &4),
+            (&3, &5),
+            (&4, &1),
+            (&5, &2)
+        ]);
+
+        cursor.seek(5);
+        let pairs: Vec<_> = cursor.pairs_at_distance(0).collect();
+        assert_eq!(pairs, vec![
+            (&5, &5),
+            (&1, &1),
+            (&2, &2),
+            (&3, &3),
+            (&4, &4)
+        ]);
+    }
+
+    #[test]
+    fn cursor_pairs_at_distance_wraps() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        // Distances beyond the length wrap via modulo
+        let pairs: Vec<_> = cursor.pairs_at_distance(7).collect();
+        let expected: Vec<_> = cursor.pairs_at_distance(2).collect();
+        assert_eq!(pairs, expected);
+        assert_eq!(pairs.first(), Some(&(&2, &4)));
+
+        assert_eq!(cursor.pairs_at_distance(usize::MAX).count(), 5);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.pairs_at_distance(3).next(), None);
+    }
+
+    #[test]
+    fn cursor_try_reserve() {
+        let source = vec![1, 2, 3];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        cursor.try_reserve(64).unwrap();
+        assert!(cursor.capacity() >= 67);
+        assert_eq!(cursor.get(), Some(&2));
+
+        cursor.cycle_next();
+        assert_eq!(cursor.get(), Some(&3));
+
+        let _ = cursor.try_reserve(usize::MAX).unwrap_err();
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+        assert_eq!(cursor.pos, Some(2));
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn cursor_seek_empty_vec() {
-        let source: Vec<usize> = vec![];
+    fn cursor_jitter_pos() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let source = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let mut cursor = CycleCursor::from(source.clone());
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..32 {
+            cursor.set_pos(Some(5)).unwrap();
+            cursor.jitter_pos(2, &mut rng);
+            assert!(cursor.pos.is_some_and(|pos| (3..=7).contains(&pos)));
+        }
+
+        // Cursor on None jitters around the first element, wrapping
+        for _ in 0..32 {
+            cursor.set_pos(None).unwrap();
+            cursor.jitter_pos(1, &mut rng);
+            assert!(matches!(cursor.pos, Some(9 | 0 | 1)));
+        }
+
+        cursor.set_pos(Some(4)).unwrap();
+        cursor.jitter_pos(0, &mut rng);
+        assert_eq!(cursor.pos, Some(4));
+        assert_eq!(cursor.inner, source);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.jitter_pos(3, &mut rng);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_run_length_from_cursor() {
+        let source = vec!['a', 'a', 'b', 'c', 'c', 'c', 'a'];
         let mut cursor = CycleCursor::from(source);
 
-        assert_eq!(cursor.pos, None);
+        let runs = cursor.run_length_from_cursor();
+        assert_eq!(runs, vec![(&'a', 2), (&'b', 1), (&'c', 3), (&'a', 1)]);
+
+        // Equal runs across the wrap boundary are kept separate
+        cursor.seek(6);
+        let runs = cursor.run_length_from_cursor();
+        assert_eq!(runs, vec![(&'c', 1), (&'a', 3), (&'b', 1), (&'c', 2)]);
+
+        let total: usize = runs.iter().map(|&(_, count)| count).sum();
+        assert_eq!(total, 7);
+    }
+
+    #[test]
+    fn cursor_run_length_from_cursor_empty() {
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty_cursor.run_length_from_cursor().is_empty());
+
+        let cursor = CycleCursor::from(vec![4, 4, 4]);
+        assert_eq!(cursor.run_length_from_cursor(), vec![(&4, 3)]);
+    }
+
+    #[test]
+    fn cursor_shift_to_match() {
+        let cursor = CycleCursor::from(vec![1, 2, 3, 4, 5, 6]);
+
+        for shift in -2..=3 {
+            let mut target = cursor.clone();
+            target.scroll(shift);
+            assert_eq!(cursor.shift_to_match(&target), Some(shift));
+        }
+
+        // Periodic sequences match at the smallest rotation
+        let cursor = CycleCursor::from(vec![1, 2, 1, 2, 1, 2]);
+        let target = CycleCursor::from(vec![2, 1, 2, 1, 2, 1]);
+        assert_eq!(cursor.shift_to_match(&target), Some(1));
+        assert_eq!(cursor.shift_to_match(&cursor), Some(0));
+    }
+
+    #[test]
+    fn cursor_shift_to_match_none() {
+        let cursor = CycleCursor::from(vec![1, 2, 3, 4]);
+
+        assert_eq!(
+            cursor.shift_to_match(&CycleCursor::from(vec![2, 1, 3, 4])),
+            None
+        );
+        assert_eq!(
+            cursor.shift_to_match(&CycleCursor::from(vec![1, 2, 3])),
+            None
+        );
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(
+            empty_cursor.shift_to_match(&CycleCursor::from(Vec::new())),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn cursor_scan_back_from_cursor() {
+        let source = vec![2, 3, 5, 7, 11];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        let products = cursor.scan_back_from_cursor(1, |product, item| {
+            *product *= item;
+            Some(*product)
+        });
+
+        let mut expected = Vec::new();
+        let mut product = 1;
+        for index in [2, 1, 0, 4, 3] {
+            product *= cursor.inner[index];
+            expected.push(product);
+        }
+        assert_eq!(products, expected);
+        assert_eq!(products, vec![5, 15, 30, 330, 2310]);
+    }
+
+    #[test]
+    fn cursor_scan_back_from_cursor_stops_early() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(-1);
+
+        let items = cursor.scan_back_from_cursor((), |(), &item| (item != 4).then_some(item));
+        assert_eq!(items, vec![3, 2, 1]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        let items = empty_cursor.scan_back_from_cursor((), |(), &item| Some(item));
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn cursor_get_indexed() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.get_indexed(), None);
+
+        cursor.seek(-1);
+        assert_eq!(cursor.get_indexed(), Some((2, &3)));
+
+        // Stale position returns None instead of panicking
+        cursor.truncate(2);
+        assert_eq!(cursor.get_indexed(), None);
+        assert_panic(|| cursor.get());
+    }
+
+    #[test]
+    fn cursor_rotate_to_longest_run() {
+        // Longest run wraps around the boundary
+        let source = vec![7, 7, 1, 2, 2, 3, 7, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        cursor.rotate_to_longest_run();
+        assert_eq!(cursor.inner, vec![7, 7, 7, 7, 1, 2, 2, 3]);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.get(), Some(&7));
+
+        // Ties pick the run starting at the lowest index
+        let mut cursor = CycleCursor::from(vec![1, 2, 2, 3, 4, 4]);
+        cursor.rotate_to_longest_run();
+        assert_eq!(cursor.inner, vec![2, 2, 3, 4, 4, 1]);
+    }
+
+    #[test]
+    fn cursor_rotate_to_longest_run_uniform() {
+        let mut cursor = CycleCursor::from(vec![5, 5, 5]);
+        cursor.rotate_to_longest_run();
+        assert_eq!(cursor.inner, vec![5, 5, 5]);
+        assert_eq!(cursor.pos, Some(0));
+
+        let mut cursor = CycleCursor::from(vec![1, 2, 3]);
+        cursor.rotate_to_longest_run();
+        assert_eq!(cursor.inner, vec![1, 2, 3]);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.rotate_to_longest_run();
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_export_window() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        assert_eq!(cursor.export_window(0, 3), vec![2, 3, 4]);
+        assert_eq!(cursor.export_window(-2, 4), vec![5, 1, 2, 3]);
+        assert_eq!(cursor.export_window(-7, 2), vec![5, 1]);
+        assert_eq!(cursor.export_window(2, 0), Vec::<usize>::new());
+
+        // Counts exceeding the length repeat elements
+        assert_eq!(cursor.export_window(1, 12), vec![
+            3, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4
+        ]);
+
+        // Starts at the same element as peek
+        for offset in -5..6 {
+            assert_eq!(cursor.export_window(offset, 1).first(), cursor.peek(offset));
+        }
+    }
+
+    #[test]
+    fn cursor_export_window_no_pos() {
+        let source = vec![1, 2, 3];
+        let cursor = CycleCursor::from(source);
+
+        assert_eq!(cursor.export_window(1, 2), vec![1, 2]);
+        assert_eq!(cursor.export_window(-1, 2), vec![2, 3]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty_cursor.export_window(1, 3).is_empty());
+    }
+
+    #[test]
+    fn cursor_is_palindrome() {
+        let mut cursor = CycleCursor::from(vec![1, 2, 3, 2, 1]);
+        assert!(cursor.is_palindrome());
+
+        // Independent of the cursor position
+        cursor.seek(2);
+        assert!(cursor.is_palindrome());
+
+        assert!(CycleCursor::from(vec![4, 4]).is_palindrome());
+        assert!(!CycleCursor::from(vec![1, 2, 3, 1]).is_palindrome());
+        assert!(CycleCursor::from(vec![1]).is_palindrome());
+        assert!(CycleCursor::from(Vec::<usize>::new()).is_palindrome());
+    }
+
+    #[test]
+    fn cursor_is_palindrome_from_cursor() {
+        let mut cursor = CycleCursor::from(vec![3, 1, 2, 1, 3, 4]);
+        assert!(!cursor.is_palindrome_from_cursor());
+
+        // Rotated view reads 1 3 4 3 1 2
+        cursor.seek(4);
+        assert!(!cursor.is_palindrome_from_cursor());
+
+        // Rotated view reads 1 2 3 2 1
+        let mut cursor = CycleCursor::from(vec![3, 2, 1, 1, 2]);
+        cursor.seek(4);
+        assert!(cursor.is_palindrome_from_cursor());
+        assert!(!cursor.is_palindrome());
+
+        assert!(CycleCursor::from(Vec::<usize>::new()).is_palindrome_from_cursor());
+        assert!(CycleCursor::from(vec![7]).is_palindrome_from_cursor());
+    }
+
+    #[test]
+    fn cursor_merge_sorted() {
+        let mut cursor = CycleCursor::from(vec![1, 3, 3, 8]);
+        let mut other = CycleCursor::from(vec![0, 3, 5, 8, 10]);
+        cursor.seek(2);
+        other.seek(1);
+
+        let merged = cursor.merge_sorted(other);
+        assert_eq!(merged.inner, vec![0, 1, 3, 3, 3, 5, 8, 8, 10]);
+        assert_eq!(merged.pos, None);
+    }
+
+    #[test]
+    fn cursor_merge_sorted_empty() {
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        let merged = empty_cursor.merge_sorted(CycleCursor::from(vec![1, 2]));
+        assert_eq!(merged.inner, vec![1, 2]);
+
+        let merged = CycleCursor::from(vec![1, 2]).merge_sorted(CycleCursor::from(Vec::new()));
+        assert_eq!(merged.inner, vec![1, 2]);
+
+        let merged =
+            CycleCursor::from(Vec::<usize>::new()).merge_sorted(CycleCursor::from(Vec::new()));
+        assert!(merged.inner.is_empty());
+        assert_eq!(merged.pos, None);
+    }
+
+    #[test]
+    fn cursor_peek_indexed() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+
+        // Cursor on None peeks like peek
+        assert_eq!(cursor.peek_indexed(1), Ok((0, &1)));
+
+        cursor.seek(3);
+        for offset in -5..=5 {
+            let (index, item) = cursor.peek_indexed(offset).unwrap();
+            assert_eq!(Some(item), cursor.peek(offset));
+
+            let mut moved = cursor.clone();
+            moved.set_pos(Some(index)).unwrap();
+            assert_eq!(moved.get(), Some(item));
+        }
+
+        assert_eq!(cursor.peek_indexed(-12), Ok((0, &1)));
+        assert_eq!(
+            cursor.peek_indexed(isize::MAX),
+            cursor.peek_indexed(isize::MAX % 5)
+        );
+    }
+
+    #[test]
+    fn cursor_peek_indexed_empty() {
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+
+        assert_eq!(empty_cursor.peek_indexed(0), Err(CursorError::Empty));
+        assert_eq!(empty_cursor.peek_indexed(-3), Err(CursorError::Empty));
+    }
+
+    #[test]
+    fn cursor_collect_until_repeat() {
+        let source = vec![1, 2, 3, 1, 2];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.collect_until_repeat(), vec![&1, &2, &3]);
+
+        // Walks across the wrap from the cursor
+        cursor.seek(3);
+        assert_eq!(cursor.collect_until_repeat(), vec![&3, &1, &2]);
         cursor.seek(2);
+        assert_eq!(cursor.collect_until_repeat(), vec![&2, &1]);
+    }
+
+    #[test]
+    fn cursor_collect_until_repeat_distinct() {
+        let source = vec![4, 5, 6, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+        assert_eq!(cursor.collect_until_repeat(), vec![&6, &7, &4, &5]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(empty_cursor.collect_until_repeat().is_empty());
+    }
+
+    #[test]
+    fn cursor_fill() {
+        let source = vec![1, 2, 3, 4];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        cursor.fill(9);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get(), Some(&9));
+        assert_eq!(cursor.inner, vec![9, 9, 9, 9]);
+
+        let mut next = 0;
+        cursor.fill_with(|| {
+            next += 2;
+            next
+        });
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.get(), Some(&6));
+        assert_eq!(cursor.inner, vec![2, 4, 6, 8]);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.fill(1);
+        assert!(empty_cursor.is_empty());
+        assert_eq!(empty_cursor.get(), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn cursor_reshuffle_keeping_current() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let source = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut cursor = CycleCursor::from(source.clone());
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for pos in 0..source.len() {
+            cursor.set_pos(Some(pos)).unwrap();
+            let pointed = *cursor.get().unwrap();
+
+            cursor.reshuffle_keeping_current(&mut rng);
+            assert_eq!(cursor.pos, Some(pos));
+            assert_eq!(cursor.get(), Some(&pointed));
+
+            let mut sorted = cursor.inner.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, source);
+        }
+
+        // Cursor on None shuffles everything
+        cursor.set_pos(None).unwrap();
+        cursor.reshuffle_keeping_current(&mut rng);
         assert_eq!(cursor.pos, None);
-        assert_eq!(cursor.get(), None);
+        let mut sorted = cursor.inner.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, source);
+
+        let mut single_cursor = CycleCursor::from(vec![1]);
+        single_cursor.cycle_next();
+        single_cursor.reshuffle_keeping_current(&mut rng);
+        assert_eq!(single_cursor.get(), Some(&1));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.reshuffle_keeping_current(&mut rng);
+        assert!(empty_cursor.is_empty());
     }
 
     #[test]
-    fn cursor_get_undefined_behavior() {
+    fn cursor_availability() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+
+        assert_eq!(
+            cursor.set_availability(vec![true, false]),
+            Err(CursorError::OutOfBounds)
+        );
+
+        // Without a mask every element is available
+        assert_eq!(cursor.cycle_next_available(), Some(&1));
+        assert_eq!(cursor.cycle_prev_available(), Some(&6));
+
+        cursor
+            .set_availability(vec![false, false, true, false, false, true])
+            .unwrap();
+        assert_eq!(cursor.cycle_next_available(), Some(&3));
+        assert_eq!(cursor.cycle_next_available(), Some(&6));
+        assert_eq!(cursor.cycle_next_available(), Some(&3));
+        assert_eq!(cursor.cycle_prev_available(), Some(&6));
+        assert_eq!(cursor.cycle_prev_available(), Some(&3));
+        assert_eq!(cursor.pos, Some(2));
+
+        // A single available position is reached after a full wrap
+        cursor
+            .set_availability(vec![false, false, true, false, false, false])
+            .unwrap();
+        assert_eq!(cursor.cycle_next_available(), Some(&3));
+        assert_eq!(cursor.cycle_prev_available(), Some(&3));
+
+        cursor.set_availability(vec![false; 6]).unwrap();
+        assert_eq!(cursor.cycle_next_available(), None);
+        assert_eq!(cursor.cycle_prev_available(), None);
+        assert_eq!(cursor.pos, Some(2));
+
+        cursor.clear_availability();
+        assert_eq!(cursor.cycle_next_available(), Some(&4));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.set_availability(Vec::new()), Ok(()));
+        assert_eq!(empty_cursor.cycle_next_available(), None);
+        assert_eq!(empty_cursor.cycle_prev_available(), None);
+    }
+
+    #[test]
+    fn cursor_ring_diagram() {
         let source = vec![1, 2, 3, 4];
         let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.ring_diagram(), "1-2-3-4-> (no cursor)");
+
+        cursor.cycle_next();
+        assert_eq!(cursor.ring_diagram(), "[1]-2-3-4->");
+        cursor.cycle_prev();
+        assert_eq!(cursor.ring_diagram(), "1-2-3-[4]->");
+
+        // A stale cursor is not drawn
+        cursor.inner.truncate(2);
+        assert_eq!(cursor.ring_diagram(), "1-2-> (no cursor)");
+
+        let mut single_cursor = CycleCursor::from(vec!["a"]);
+        single_cursor.cycle_next();
+        assert_eq!(single_cursor.ring_diagram(), "[a]->");
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.ring_diagram(), "(empty)");
+    }
+
+    #[test]
+    fn cursor_rotate_front_to_any() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut cursor = CycleCursor::from(source.clone());
+        cursor.seek(2);
+
+        // 7 is two steps back, 4 is three steps forward
+        assert!(cursor.rotate_front_to_any(&[4, 7]));
+        assert_eq!(cursor.inner, vec![7, 8, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(cursor.pos, Some(3));
+        assert_eq!(cursor.get().unwrap(), &2);
+
+        // An anchor already at the front needs no rotation
+        assert!(cursor.rotate_front_to_any(&[7, 3]));
+        assert_eq!(cursor.inner, vec![7, 8, 1, 2, 3, 4, 5, 6]);
+
+        // Equal distances rotate forward
+        assert!(cursor.rotate_front_to_any(&[1, 5]));
+        assert_eq!(cursor.inner, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(cursor.get().unwrap(), &2);
+
+        assert!(!cursor.rotate_front_to_any(&[0, 9]));
+        assert!(!cursor.rotate_front_to_any(&[]));
+        assert_eq!(cursor.inner, source);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert!(!empty_cursor.rotate_front_to_any(&[1]));
+    }
+
+    #[test]
+    fn cursor_try_for_each_from_cursor() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        let mut visited = Vec::new();
+        let result = cursor.try_for_each_from_cursor(|&item| {
+            visited.push(item);
+            if item == 1 {
+                Err("bad element")
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(result, Err("bad element"));
+        assert_eq!(visited, vec![4, 5, 1]);
+
+        let mut visited = Vec::new();
+        let result = cursor.try_for_each_from_cursor(|&item| {
+            visited.push(item);
+            Ok::<_, ()>(())
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(visited, vec![4, 5, 1, 2, 3]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(
+            empty_cursor.try_for_each_from_cursor(|_| Err::<(), _>(())),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn cursor_seek_counting_wraps() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+
+        // Starting before the first element
+        assert_eq!(cursor.seek_counting_wraps(5), 0);
+        assert_eq!(cursor.pos, Some(4));
+
+        cursor.set_pos(None).unwrap();
+        assert_eq!(cursor.seek_counting_wraps(6), 1);
+        assert_eq!(cursor.pos, Some(0));
+
+        assert_eq!(cursor.seek_counting_wraps(4), 0);
+        assert_eq!(cursor.pos, Some(4));
+        assert_eq!(cursor.seek_counting_wraps(1), 1);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.seek_counting_wraps(17), 3);
+        assert_eq!(cursor.pos, Some(2));
+        assert_eq!(cursor.seek_counting_wraps(0), 0);
+        assert_eq!(cursor.pos, Some(2));
 
+        // Backward movement crossing the boundary
+        assert_eq!(cursor.seek_counting_wraps(-2), 0);
+        assert_eq!(cursor.pos, Some(0));
+        assert_eq!(cursor.seek_counting_wraps(-1), 1);
+        assert_eq!(cursor.pos, Some(4));
+        assert_eq!(cursor.seek_counting_wraps(-15), 3);
+        assert_eq!(cursor.pos, Some(4));
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.seek_counting_wraps(7), 0);
+        assert_eq!(empty_cursor.pos, None);
+    }
+
+    #[test]
+    fn cursor_windows_cyclic_flagged() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
         cursor.seek(3);
-        let _ = cursor.inner.remove(0);
-        let _ = cursor.inner.remove(0);
 
-        assert_panic(|| cursor.get());
+        let windows: Vec<_> = cursor.windows_cyclic_flagged(3).collect();
+        assert_eq!(windows, vec![
+            (false, vec![&3, &4, &5]),
+            (true, vec![&4, &5, &1]),
+            (true, vec![&5, &1, &2]),
+            (false, vec![&1, &2, &3]),
+            (false, vec![&2, &3, &4]),
+        ]);
+
+        for size in 1..=5 {
+            let wrapping = cursor
+                .windows_cyclic_flagged(size)
+                .filter(|(wraps, _)| *wraps)
+                .count();
+            assert_eq!(wrapping, size - 1);
+        }
+
+        // Windows longer than the ring always wrap
+        assert!(cursor.windows_cyclic_flagged(6).all(|(wraps, _)| wraps));
+
+        assert_eq!(cursor.windows_cyclic_flagged(0).count(), 0);
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.windows_cyclic_flagged(2).count(), 0);
+    }
+
+    #[test]
+    fn cursor_clone_rotated() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source.clone());
+        cursor.seek(4);
+
+        let forked = cursor.clone_rotated();
+        assert_eq!(forked.inner, vec![4, 5, 1, 2, 3]);
+        assert_eq!(forked.pos, Some(0));
+        assert_eq!(forked.get(), cursor.get());
+
+        // The original is left untouched
+        assert_eq!(cursor.inner, source);
+        assert_eq!(cursor.pos, Some(3));
+
+        cursor.set_pos(None).unwrap();
+        let forked = cursor.clone_rotated();
+        assert_eq!(forked.inner, source);
+        assert_eq!(forked.pos, Some(0));
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        let forked = empty_cursor.clone_rotated();
+        assert!(forked.is_empty());
+        assert_eq!(forked.pos, None);
+    }
+
+    #[test]
+    fn cursor_step_iter() {
+        let source = vec![1, 2, 3, 4, 5, 6, 7];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(4);
+
+        // Coprime steps visit every element once
+        let stepped: Vec<_> = cursor.step_iter(3).collect();
+        assert_eq!(stepped, vec![&4, &7, &3, &6, &2, &5, &1]);
+        let stepped: Vec<_> = cursor.step_iter(10).collect();
+        assert_eq!(stepped, vec![&4, &7, &3, &6, &2, &5, &1]);
+        let stepped: Vec<_> = cursor.step_iter(1).collect();
+        assert_eq!(stepped, vec![&4, &5, &6, &7, &1, &2, &3]);
+    }
+
+    #[test]
+    fn cursor_step_iter_non_coprime() {
+        let source = vec![1, 2, 3, 4, 5, 6];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(2);
+
+        let stepped: Vec<_> = cursor.step_iter(2).collect();
+        assert_eq!(stepped, vec![&2, &4, &6, &2, &4, &6]);
+        let stepped: Vec<_> = cursor.step_iter(3).collect();
+        assert_eq!(stepped, vec![&2, &5, &2, &5, &2, &5]);
+        let stepped: Vec<_> = cursor.step_iter(6).collect();
+        assert_eq!(stepped, vec![&2; 6]);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.step_iter(2).count(), 0);
+
+        assert_panic(|| {
+            drop(cursor.step_iter(0));
+        });
+    }
+
+    #[test]
+    fn cursor_summary() {
+        let source = vec![1, 2, 3, 4, 5];
+        let mut cursor = CycleCursor::from(source);
+        assert_eq!(cursor.summary(), CursorSummary {
+            len: 5,
+            pos: None,
+            is_empty: false,
+            is_initialized: false,
+            remaining_forward: None,
+            remaining_backward: None,
+        });
+
+        cursor.cycle_prev();
+        assert_eq!(cursor.summary(), CursorSummary {
+            len: 5,
+            pos: Some(4),
+            is_empty: false,
+            is_initialized: true,
+            remaining_forward: Some(0),
+            remaining_backward: Some(4),
+        });
+
+        // A stale cursor keeps its raw position
+        cursor.inner.truncate(3);
+        let summary = cursor.summary();
+        assert_eq!(summary.len, 3);
+        assert_eq!(summary.pos, Some(4));
+        assert!(summary.is_initialized);
+        assert_eq!(summary.remaining_forward, None);
+
+        let empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        assert_eq!(empty_cursor.summary(), CursorSummary {
+            len: 0,
+            pos: None,
+            is_empty: true,
+            is_initialized: false,
+            remaining_forward: None,
+            remaining_backward: None,
+        });
+    }
+
+    #[test]
+    fn cursor_rotate_to_max() {
+        let source = vec![3, 1, 9, 4, 9, 2];
+        let mut cursor = CycleCursor::from(source.clone());
+        cursor.seek(4);
+
+        cursor.rotate_to_max();
+        assert_eq!(cursor.inner, vec![9, 4, 9, 2, 3, 1]);
+        assert_eq!(cursor.pos, Some(1));
+        assert_eq!(cursor.get().unwrap(), &4);
+
+        // Already anchored
+        cursor.rotate_to_max();
+        assert_eq!(cursor.inner, vec![9, 4, 9, 2, 3, 1]);
+        assert_eq!(cursor.get().unwrap(), &4);
+
+        // Cursor on None only rotates the elements
+        let mut cursor = CycleCursor::from(source);
+        cursor.rotate_to_max();
+        assert_eq!(cursor.inner, vec![9, 4, 9, 2, 3, 1]);
+        assert_eq!(cursor.pos, None);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.rotate_to_max();
+        assert!(empty_cursor.is_empty());
+    }
+
+    #[test]
+    fn cursor_rotate_to_min() {
+        let source = vec![6, 2, 8, 0, 5, 0];
+        let mut cursor = CycleCursor::from(source);
+        cursor.seek(3);
+
+        cursor.rotate_to_min();
+        assert_eq!(cursor.inner, vec![0, 5, 0, 6, 2, 8]);
+        assert_eq!(cursor.pos, Some(5));
+        assert_eq!(cursor.get().unwrap(), &8);
+
+        let mut empty_cursor = CycleCursor::from(Vec::<usize>::new());
+        empty_cursor.rotate_to_min();
+        assert!(empty_cursor.is_empty());
     }
 }